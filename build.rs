@@ -0,0 +1,168 @@
+//! Turns `templates/manifest.json` into a `UiComponent` constant per template, with the
+//! template file embedded via `include_str!`. Fails the build when a template's
+//! `{{devano:<key>}}` placeholders don't match the options its manifest entry declares.
+//! Also generates the `Icons` atom enum - one variant per template in `atoms/icons`, named
+//! after its file - so icons added by `devano icons --templates` register themselves.
+use serde_json::Value;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let templates = Path::new(&manifest_dir).join("templates");
+    println!("cargo:rerun-if-changed=templates");
+
+    let manifest_path = templates.join("manifest.json");
+    let manifest: Value = serde_json::from_str(
+        &fs::read_to_string(&manifest_path).expect("templates/manifest.json should exist"),
+    )
+    .expect("templates/manifest.json should be valid JSON");
+
+    let mut generated = String::new();
+    let mut consts = Vec::new();
+    let mut icons = Vec::new();
+    for component in manifest["components"]
+        .as_array()
+        .expect("manifest needs a `components` array")
+    {
+        let field = |key: &str| {
+            component[key]
+                .as_str()
+                .unwrap_or_else(|| panic!("component is missing `{}`: {}", key, component))
+        };
+        consts.push(format!("&{}", field("const")));
+        if field("folder_path") == "atoms/icons" {
+            let variant = field("filename")
+                .split('.')
+                .next()
+                .expect("split always yields one part")
+                .to_string();
+            icons.push((variant, field("const").to_string()));
+        }
+        let template = templates.join(field("folder_path")).join(field("filename"));
+        assert!(template.exists(), "no template at {:?}", template);
+        let npm_deps: Vec<String> = component["npm_deps"]
+            .as_array()
+            .map(|deps| {
+                deps.iter()
+                    .map(|dep| format!("{:?}", dep.as_str().unwrap()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let options = component["options"].as_array().cloned().unwrap_or_default();
+        check_placeholders(&template, &options);
+
+        writeln!(
+            generated,
+            "pub const {}: UiComponent = UiComponent {{\n    name: {:?},\n    description: {:?},\n    long_description: {:?},\n    filename: {:?},\n    contents: include_str!({:?}),\n    folder_path: {:?},\n    npm_deps: &[{}],\n    options: &[{}],\n}};\n",
+            field("const"),
+            field("name"),
+            field("description"),
+            field("long_description"),
+            field("filename"),
+            template.display().to_string(),
+            field("folder_path"),
+            npm_deps.join(", "),
+            options.iter().map(render_option).collect::<Vec<_>>().join(", "),
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        generated,
+        "/// Every template in the manifest, in manifest order.\npub const ALL_TEMPLATES: &[&UiComponent] = &[{}];",
+        consts.join(", ")
+    )
+    .unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("templates.rs"), generated)
+        .expect("could not write generated templates");
+    fs::write(Path::new(&out_dir).join("icons.rs"), icons_enum(&icons))
+        .expect("could not write generated icons");
+}
+
+fn icons_enum(icons: &[(String, String)]) -> String {
+    let variants: Vec<String> = icons
+        .iter()
+        .map(|(variant, _)| format!("    {},\n", variant))
+        .collect();
+    let arms: Vec<String> = icons
+        .iter()
+        .map(|(variant, constant)| {
+            format!(
+                "            Icons::{} => &crate::library::client::templates::{},\n",
+                variant, constant
+            )
+        })
+        .collect();
+    format!(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]\npub enum Icons {{\n{}}}\n\nimpl Icons {{\n    pub fn get_ui(&self) -> &'static UiComponent {{\n        match self {{\n{}        }}\n    }}\n}}\n",
+        variants.concat(),
+        arms.concat()
+    )
+}
+
+fn text(value: &Value, key: &str) -> String {
+    value[key]
+        .as_str()
+        .unwrap_or_else(|| panic!("option is missing `{}`: {}", key, value))
+        .to_string()
+}
+
+fn render_option(option: &Value) -> String {
+    let choices: Vec<String> = option["choices"]
+        .as_array()
+        .filter(|choices| !choices.is_empty())
+        .unwrap_or_else(|| panic!("option needs at least one choice: {}", option))
+        .iter()
+        .map(|choice| {
+            format!(
+                "OptionChoice {{ id: {:?}, description: {:?}, text: {:?} }}",
+                text(choice, "id"),
+                text(choice, "description"),
+                text(choice, "text"),
+            )
+        })
+        .collect();
+    format!(
+        "ComponentOption {{ key: {:?}, prompt: {:?}, choices: &[{}] }}",
+        text(option, "key"),
+        text(option, "prompt"),
+        choices.join(", ")
+    )
+}
+
+fn check_placeholders(template: &Path, options: &[Value]) {
+    let contents = fs::read_to_string(template).expect("template should be readable");
+    let mut used: Vec<&str> = Vec::new();
+    let mut rest = contents.as_str();
+    while let Some(start) = rest.find("{{devano:") {
+        let after = &rest[start + "{{devano:".len()..];
+        let end = after
+            .find("}}")
+            .unwrap_or_else(|| panic!("unclosed placeholder in {:?}", template));
+        used.push(&after[..end]);
+        rest = &after[end..];
+    }
+    let declared: Vec<String> = options.iter().map(|option| text(option, "key")).collect();
+    for key in &used {
+        assert!(
+            declared.iter().any(|declared| declared == key),
+            "{:?} uses {{{{devano:{}}}}} but the manifest declares no `{}` option",
+            template,
+            key,
+            key
+        );
+    }
+    for key in &declared {
+        assert!(
+            used.contains(&key.as_str()),
+            "{:?} never uses its `{}` option",
+            template,
+            key
+        );
+    }
+}
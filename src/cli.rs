@@ -1,4 +1,6 @@
-use clap::{Args, Parser, Subcommand}; // Bring clap derive macros into scope [oai_citation_attribution:0‡docs.rs](https://docs.rs/clap/latest/clap/#:~:text=use%20clap%3A%3AParser%3B)
+use crate::library::package_manager::PackageManagers;
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
 
 /// Define the command-line interface using clap derive macros
 #[derive(Parser)]
@@ -11,6 +13,10 @@ use clap::{Args, Parser, Subcommand}; // Bring clap derive macros into scope [oa
     propagate_version = true    // Propagate version to subcommands
 )]
 pub struct Cli {
+    /// Package manager to install with - detected from the project's lockfile when omitted
+    #[arg(long = "pm", global = true, value_enum)]
+    pub package_manager: Option<PackageManagers>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -20,7 +26,7 @@ pub enum Commands {
     #[command(name = "ui", about = "UI-related tooling")]
     Ui,
 
-    #[command(name = "add", about = "Internal Tooling for installing deps")]
+    #[command(name = "add", about = "Install a feature or an atom by name")]
     Add(AddArgs),
 
     #[command(name = "feat", about = "Add a feature")]
@@ -28,9 +34,47 @@ pub enum Commands {
 
     #[command(name = "new", about = "Create a new Devano monorepo")]
     New,
+
+    #[command(
+        name = "sync",
+        about = "Pull components from a workshop project back into the templates"
+    )]
+    Sync(SyncArgs),
+
+    #[command(
+        name = "icons",
+        about = "Turn a folder of SVGs into icon components that share one props interface"
+    )]
+    Icons(IconsArgs),
 }
 
 #[derive(Args)]
 pub struct AddArgs {
     pub value: String,
 }
+
+#[derive(Args)]
+pub struct SyncArgs {
+    /// A Solid project whose `src/devano` holds the components being worked on
+    pub workshop: PathBuf,
+
+    /// The templates folder to update - by default the `templates/` of the devano checkout
+    /// you're running from
+    #[arg(long)]
+    pub templates: Option<PathBuf>,
+
+    /// Print the summary without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct IconsArgs {
+    /// A folder of `.svg` files - each becomes a component named after its file
+    pub dir: PathBuf,
+
+    /// Add the icons to a templates folder instead of this project - the `templates/` of
+    /// the devano checkout you're running from when no path is given
+    #[arg(long)]
+    pub templates: Option<Option<PathBuf>>,
+}
@@ -1,4 +1,7 @@
+use crate::library::client::components::atoms::registry::Atoms;
 use crate::library::client::components::features::registry::Features;
+use crate::library::client::components::plan::{InstallPlan, InstallStep};
+use crate::library::client::components::remote;
 use anyhow::Result;
 
 pub fn add(args: &str) -> Result<()> {
@@ -8,7 +11,33 @@ pub fn add(args: &str) -> Result<()> {
             println!("Feature 'auth' installed successfully.");
         }
         _ => {
-            println!("Unknown feature: {}", args);
+            if let Some(atom) = Atoms::from_name(args) {
+                atom.install()?;
+                println!("Atom '{}' installed successfully.", args);
+            } else if let Some(component) = remote::find(args)? {
+                InstallPlan::new(&[InstallStep::Remote(component)])?.install()?;
+                println!(
+                    "Component '{}' installed successfully.",
+                    component.qualified_name()
+                );
+            } else {
+                println!("Unknown feature or component: {}", args);
+                println!("Available atoms:");
+                for (name, description) in Atoms::descriptions() {
+                    println!("  {:<16} {}", name, description);
+                }
+                let remote_components = remote::remote_components()?;
+                if !remote_components.is_empty() {
+                    println!("Available registry components:");
+                    for component in remote_components {
+                        println!(
+                            "  {:<16} {}",
+                            component.qualified_name(),
+                            component.description
+                        );
+                    }
+                }
+            }
         }
     }
     Ok(())
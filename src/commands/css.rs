@@ -1,5 +1,6 @@
 use crate::library::client;
 use crate::library::client::colors::named_palettes::NamedPalette;
+use crate::library::client::colors::reads::read_devano_palette_css;
 use crate::library::client::colors::writes::{write_devano_palette_css, write_devano_palette_rust};
 use anyhow::Result;
 use cliclack::{input, intro, outro, select};
@@ -20,6 +21,11 @@ pub fn add_guided_palette() -> Result<()> {
             "Add a named palette",
             "Open the guided palette selector",
         ),
+        (
+            "Load the project palette",
+            "Load the project palette",
+            "Read ./src/css/devano/palette.css back in to preview or re-export it",
+        ),
         ("Cancel", "Cancel", "Exit the program"),
     ];
     let choice = select("What would you like to do?")
@@ -33,6 +39,9 @@ pub fn add_guided_palette() -> Result<()> {
         "Add a named palette" => {
             add_named_palette()?;
         }
+        "Load the project palette" => {
+            load_project_palette()?;
+        }
         "Cancel" => {
             outro("Operation canceled.")?;
         }
@@ -82,6 +91,42 @@ pub fn add_named_palette() -> Result<()> {
     Ok(())
 }
 
+pub fn load_project_palette() -> Result<()> {
+    let palette = read_devano_palette_css()?;
+    let rows = [
+        ("kora", palette.kora.hexes().join(" ")),
+        ("aleva", palette.aleva.hexes().join(" ")),
+        ("ara", palette.ara.hexes().join(" ")),
+        ("ene", palette.ene.hexes().join(" ")),
+        ("izi", palette.izi.hexes().join(" ")),
+        ("ona", palette.ona.hexes().join(" ")),
+        ("uvo", palette.uvo.hexes().join(" ")),
+        ("bala", palette.bala.hexes().join(" ")),
+    ];
+    for (name, swatches) in rows {
+        println!("{:>6}: {}", name, swatches);
+    }
+
+    let export_options = get_export_options();
+    let export_choice = select("How would you like to export the palette?")
+        .items(&export_options)
+        .interact()?;
+
+    match export_choice {
+        "Export to Rust" => {
+            write_devano_palette_rust(&palette)?;
+        }
+        "Export to CSS" => {
+            write_devano_palette_css(&palette)?;
+        }
+        "Go Back" => {
+            add_guided_palette()?;
+        }
+        _ => unreachable!(),
+    };
+    Ok(())
+}
+
 pub fn split_complexity() -> Result<()> {
     let complexity_levels = [
         (
@@ -0,0 +1,47 @@
+use crate::library::client::components::sync::find_templates_path;
+use crate::library::client::icons::writes::{
+    ICONS_FOLDER, read_icons, write_project_icons, write_template_icons,
+};
+use anyhow::{Result, anyhow};
+use std::path::Path;
+
+/// `templates` is `None` to install into the current project, `Some(None)` for the
+/// `templates/` of the devano checkout you're running from, or `Some(Some(path))` for
+/// another one.
+pub fn icons(dir: &Path, templates: Option<Option<&Path>>) -> Result<()> {
+    let icons = read_icons(dir)?;
+    if icons.is_empty() {
+        return Err(anyhow!("No .svg files in {:?}", dir));
+    }
+
+    match templates {
+        None => {
+            write_project_icons(&icons)?;
+            for icon in &icons {
+                println!("+ {}/{}.tsx", ICONS_FOLDER, icon.component);
+            }
+            println!(
+                "Installed {} icon(s) into client/src/devano/{}",
+                icons.len(),
+                ICONS_FOLDER
+            );
+        }
+        Some(templates) => {
+            let templates = match templates {
+                Some(templates) => templates.to_path_buf(),
+                None => find_templates_path()?,
+            };
+            let added = write_template_icons(&icons, &templates)?;
+            println!(
+                "Wrote {} icon(s) to {:?}, {} new in the manifest",
+                icons.len(),
+                templates.join(ICONS_FOLDER),
+                added
+            );
+            if added > 0 {
+                println!("Rebuild devano to register the new icons.");
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,10 @@
 pub mod add;
 pub mod css;
 pub mod feature;
+pub mod icons;
+pub mod motion;
 pub mod new;
+pub mod sync;
+pub mod tokens;
+pub mod typography;
 pub mod ui;
@@ -0,0 +1,98 @@
+use crate::library::client::motion::named_motions::NamedMotion;
+use crate::library::client::motion::transforms::{Easing, MotionSet};
+use crate::library::client::motion::writes::write_devano_motion_css;
+use anyhow::Result;
+use cliclack::{input, intro, outro, select};
+use strum::IntoEnumIterator;
+
+pub fn add_guided_motion() -> Result<()> {
+    intro("Devano Motion Tools".to_string())?;
+
+    let choices = [
+        (
+            "Make a new motion set",
+            "Make a new motion set",
+            "Pick a base duration and easing curves",
+        ),
+        (
+            "Add a named motion set",
+            "Add a named motion set",
+            "Open the guided motion preset selector",
+        ),
+        ("Cancel", "Cancel", "Exit the program"),
+    ];
+    let choice = select("What would you like to do?")
+        .items(&choices)
+        .interact()?;
+
+    match choice {
+        "Make a new motion set" => {
+            add_custom_motion()?;
+        }
+        "Add a named motion set" => {
+            add_named_motion()?;
+        }
+        "Cancel" => {
+            outro("Operation canceled.")?;
+        }
+        _ => unreachable!(),
+    };
+    Ok(())
+}
+
+pub fn add_named_motion() -> Result<()> {
+    let motion_names: Vec<(&str, &str, &str)> = NamedMotion::iter()
+        .map(|motion| {
+            let name = motion.as_str();
+            (name, name, motion.description())
+        })
+        .collect();
+
+    let selected_motion_name = select("Select a named motion set:")
+        .items(&motion_names)
+        .interact()?;
+
+    if let Some(named_motion) = NamedMotion::from_str(selected_motion_name) {
+        write_devano_motion_css(&named_motion.get_motion())?;
+    } else {
+        eprintln!("Invalid motion selection.");
+    };
+    Ok(())
+}
+
+pub fn add_custom_motion() -> Result<()> {
+    let base_ms: u32 = input("Base duration, in ms (fast/slow are derived from it):")
+        .default_input("200")
+        .validate(|input: &String| match input.parse::<u32>() {
+            Ok(ms) if (50..=1000).contains(&ms) => Ok(()),
+            _ => Err("Please enter a whole number of milliseconds between 50 and 1000."),
+        })
+        .interact()?;
+    let distance_px: u32 = input("Slide distance, in px:")
+        .default_input("8")
+        .validate(|input: &String| match input.parse::<u32>() {
+            Ok(px) if px <= 64 => Ok(()),
+            _ => Err("Please enter a whole number of pixels up to 64."),
+        })
+        .interact()?;
+
+    let motion = MotionSet {
+        fast_ms: base_ms * 3 / 5,
+        base_ms,
+        slow_ms: base_ms * 8 / 5,
+        ease_standard: get_easing("Standard easing (hover, color changes):")?,
+        ease_enter: get_easing("Enter easing (things appearing):")?,
+        ease_exit: get_easing("Exit easing (things leaving):")?,
+        distance_px,
+    };
+    write_devano_motion_css(&motion)?;
+    Ok(())
+}
+
+fn get_easing(prompt: &str) -> Result<Easing> {
+    let easings: Vec<(&str, &str, &str)> = Easing::iter()
+        .map(|easing| (easing.as_str(), easing.as_str(), easing.description()))
+        .collect();
+    let easing = select(prompt).items(&easings).interact()?;
+    Ok(Easing::from_str(easing).expect("Easing choices come from Easing::iter"))
+}
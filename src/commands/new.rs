@@ -1,4 +1,5 @@
 use crate::library::new::{client, server};
+use crate::library::package_manager;
 use anyhow::Result;
 use cliclack::{input, intro, multi_progress, outro, progress_bar};
 use std::fs;
@@ -28,8 +29,10 @@ pub fn create_devano_app_internal() -> Result<()> {
     fs::create_dir_all(&client_path)?;
     let server_path = Path::new(&project_name).join("server");
     fs::create_dir_all(&server_path)?;
-    let (client_files, client_commands) = client::make_files();
-    let (server_files, server_commands) = server::make_files();
+    // a fresh project has no lockfile yet, so this is the `--pm` flag or the default
+    let package_manager = package_manager::resolve(Path::new(&project_name));
+    let (client_files, client_commands) = client::make_files(package_manager);
+    let (server_files, server_commands) = server::make_files(package_manager);
     let total_client_length = client_files.len() as u64 + client_commands.len() as u64;
     let total_server_length = server_files.len() as u64 + server_commands.len() as u64;
     let pb1 = multi.add(progress_bar(total_client_length));
@@ -0,0 +1,52 @@
+use crate::library::client::components::sync::{SyncChange, find_templates_path, sync_workshop};
+use anyhow::Result;
+use std::path::Path;
+
+pub fn sync(workshop: &Path, templates: Option<&Path>, dry_run: bool) -> Result<()> {
+    let templates = match templates {
+        Some(templates) => templates.to_path_buf(),
+        None => find_templates_path()?,
+    };
+    let changes = sync_workshop(workshop, &templates, dry_run)?;
+
+    let mut unchanged = 0;
+    let mut updated = 0;
+    for change in &changes {
+        match change {
+            SyncChange::Unchanged(_) => unchanged += 1,
+            SyncChange::Updated {
+                path,
+                added,
+                removed,
+            } => {
+                updated += 1;
+                println!("~ {} (+{} -{})", path.display(), added, removed);
+            }
+            SyncChange::Unregistered(path) => {
+                println!(
+                    "? {} isn't registered - add it to templates/manifest.json",
+                    path.display()
+                );
+            }
+            SyncChange::Missing(path) => {
+                println!("- {} isn't in the workshop", path.display());
+            }
+            SyncChange::Rendered(path) => {
+                println!(
+                    "! {} has its install-time options filled in - restore the {{{{devano:...}}}} placeholders to sync it",
+                    path.display()
+                );
+            }
+        }
+    }
+
+    let verb = if dry_run { "would update" } else { "updated" };
+    println!(
+        "{} {} template(s) in {:?}, {} unchanged",
+        verb, updated, templates, unchanged
+    );
+    if updated > 0 && !dry_run {
+        println!("Rebuild devano to ship the new templates.");
+    }
+    Ok(())
+}
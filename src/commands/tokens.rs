@@ -0,0 +1,107 @@
+use crate::library::client::colors::named_palettes::NamedPalette;
+use crate::library::client::colors::reads::read_devano_palette_css;
+use crate::library::client::colors::transforms::DevanoPalette;
+use crate::library::client::tokens::named_tokens::NamedTokenSet;
+use crate::library::client::tokens::transforms::TokenSet;
+use crate::library::client::tokens::writes::write_devano_tokens_css;
+use anyhow::Result;
+use cliclack::{input, intro, log, outro, select};
+use strum::IntoEnumIterator;
+
+pub fn add_guided_tokens() -> Result<()> {
+    intro("Devano Layout Tokens".to_string())?;
+
+    let choices = [
+        (
+            "Make a new token set",
+            "Make a new token set",
+            "Pick a spacing unit, corner radius and shadow strength",
+        ),
+        (
+            "Add a named token set",
+            "Add a named token set",
+            "Open the guided token set selector",
+        ),
+        ("Cancel", "Cancel", "Exit the program"),
+    ];
+    let choice = select("What would you like to do?")
+        .items(&choices)
+        .interact()?;
+
+    let tokens = match choice {
+        "Make a new token set" => get_custom_tokens()?,
+        "Add a named token set" => get_named_tokens()?,
+        "Cancel" => {
+            outro("Operation canceled.")?;
+            return Ok(());
+        }
+        _ => unreachable!(),
+    };
+
+    let palette = get_shadow_palette()?;
+    write_devano_tokens_css(&tokens, &palette)?;
+    Ok(())
+}
+
+fn get_named_tokens() -> Result<TokenSet> {
+    let token_names: Vec<(&str, &str, &str)> = NamedTokenSet::iter()
+        .map(|set| {
+            let name = set.as_str();
+            (name, name, set.description())
+        })
+        .collect();
+
+    let selected = select("Select a named token set:")
+        .items(&token_names)
+        .interact()?;
+    let named =
+        NamedTokenSet::from_str(selected).expect("Token set choices come from NamedTokenSet::iter");
+    Ok(named.get_tokens())
+}
+
+fn get_custom_tokens() -> Result<TokenSet> {
+    let spacing_unit_px = get_number("Spacing unit, in px:", "4", 2.0, 8.0)?;
+    let radius_px = get_number("Default corner radius, in px:", "6", 0.0, 32.0)?;
+    let shadow_strength = get_number("Shadow strength (1 is the default):", "1", 0.0, 3.0)?;
+    Ok(TokenSet {
+        spacing_unit_px,
+        radius_px,
+        shadow_strength,
+    })
+}
+
+fn get_number(prompt: &str, default: &str, min: f32, max: f32) -> Result<f32> {
+    let number = input(prompt)
+        .default_input(default)
+        .validate(move |input: &String| match input.parse::<f32>() {
+            Ok(number) if (min..=max).contains(&number) => Ok(()),
+            _ => Err(format!(
+                "Please enter a number between {} and {}.",
+                min, max
+            )),
+        })
+        .interact()?;
+    Ok(number)
+}
+
+/// Shadows are tinted from the project's palette - fall back to a named palette if there isn't one.
+fn get_shadow_palette() -> Result<DevanoPalette> {
+    match read_devano_palette_css() {
+        Ok(palette) => Ok(palette),
+        Err(e) => {
+            log::warning(format!("Couldn't use the project palette: {}", e))?;
+            let palette_names: Vec<(&str, &str, &str)> = NamedPalette::iter()
+                .map(|palette| {
+                    let name = palette.as_str();
+                    (name, name, palette.description())
+                })
+                .collect();
+            let selected = select("Tint shadows from which named palette?")
+                .items(&palette_names)
+                .interact()?;
+            let named = NamedPalette::from_str(selected)
+                .expect("Palette choices come from NamedPalette::iter");
+            Ok(named.get_palette())
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::library::client::typography::named_scales::NamedTypeScale;
+use crate::library::client::typography::transforms::{
+    FontStackPreset, FontStacks, MONO_STACK, Ratio, TypeScale,
+};
+use crate::library::client::typography::writes::write_devano_typography_css;
+use anyhow::Result;
+use cliclack::{input, intro, outro, select};
+use strum::IntoEnumIterator;
+
+pub fn add_guided_typography() -> Result<()> {
+    intro("Devano Typography Tools".to_string())?;
+
+    let choices = [
+        (
+            "Make a new type scale",
+            "Make a new type scale",
+            "Pick a base size, ratio and font stacks",
+        ),
+        (
+            "Add a named type scale",
+            "Add a named type scale",
+            "Open the guided type scale selector",
+        ),
+        ("Cancel", "Cancel", "Exit the program"),
+    ];
+    let choice = select("What would you like to do?")
+        .items(&choices)
+        .interact()?;
+
+    match choice {
+        "Make a new type scale" => {
+            add_custom_type_scale()?;
+        }
+        "Add a named type scale" => {
+            add_named_type_scale()?;
+        }
+        "Cancel" => {
+            outro("Operation canceled.")?;
+        }
+        _ => unreachable!(),
+    };
+    Ok(())
+}
+
+pub fn add_named_type_scale() -> Result<()> {
+    let scale_names: Vec<(&str, &str, &str)> = NamedTypeScale::iter()
+        .map(|scale| {
+            let name = scale.as_str();
+            (name, name, scale.description())
+        })
+        .collect();
+
+    let selected_scale_name = select("Select a named type scale:")
+        .items(&scale_names)
+        .interact()?;
+
+    if let Some(named_scale) = NamedTypeScale::from_str(selected_scale_name) {
+        write_devano_typography_css(&named_scale.get_scale())?;
+    } else {
+        eprintln!("Invalid type scale selection.");
+    };
+    Ok(())
+}
+
+pub fn add_custom_type_scale() -> Result<()> {
+    let base_px: f32 = input("Base font size, in px:")
+        .default_input("16")
+        .validate(|input: &String| match input.parse::<f32>() {
+            Ok(size) if (10.0..=32.0).contains(&size) => Ok(()),
+            _ => Err("Please enter a number between 10 and 32."),
+        })
+        .interact()?;
+
+    let ratios: Vec<(&str, &str, &str)> = Ratio::iter()
+        .map(|ratio| (ratio.as_str(), ratio.as_str(), ratio.description()))
+        .collect();
+    let ratio = select("Scale ratio:").items(&ratios).interact()?;
+    let ratio = Ratio::from_str(ratio).expect("Ratio choices come from Ratio::iter");
+
+    let body_line_height = get_line_height("Body line height:", "1.5")?;
+    let heading_line_height = get_line_height("Heading line height:", "1.2")?;
+
+    let body = get_font_stack("Body font stack:")?;
+    let heading = get_font_stack("Heading font stack:")?;
+
+    let scale = TypeScale {
+        base_px,
+        ratio: ratio.value(),
+        body_line_height,
+        heading_line_height,
+        fonts: FontStacks {
+            body: body.stack().to_string(),
+            heading: heading.stack().to_string(),
+            mono: MONO_STACK.to_string(),
+        },
+    };
+    write_devano_typography_css(&scale)?;
+    Ok(())
+}
+
+fn get_line_height(prompt: &str, default: &str) -> Result<f32> {
+    let line_height = input(prompt)
+        .default_input(default)
+        .validate(|input: &String| match input.parse::<f32>() {
+            Ok(height) if (0.8..=2.5).contains(&height) => Ok(()),
+            _ => Err("Please enter a unitless line height between 0.8 and 2.5."),
+        })
+        .interact()?;
+    Ok(line_height)
+}
+
+fn get_font_stack(prompt: &str) -> Result<FontStackPreset> {
+    let stacks: Vec<(&str, &str, &str)> = FontStackPreset::iter()
+        .map(|stack| (stack.as_str(), stack.as_str(), stack.description()))
+        .collect();
+    let stack = select(prompt).items(&stacks).interact()?;
+    Ok(FontStackPreset::from_str(stack)
+        .expect("Font stack choices come from FontStackPreset::iter"))
+}
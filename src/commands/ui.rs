@@ -1,10 +1,8 @@
-use super::css;
+use super::{css, motion, tokens, typography};
 use crate::library::client::components::atoms;
+use crate::library::client::writes;
 use anyhow::Result;
-use cliclack::{intro, multi_progress, outro, progress_bar, select, spinner};
-use std::fs;
-use std::io;
-use std::path::Path;
+use cliclack::{intro, multi_progress, outro, progress_bar, select};
 use std::thread;
 
 pub fn guided_ui() -> Result<()> {
@@ -45,6 +43,21 @@ pub fn css_options() -> Result<()> {
     intro("Devano CSS".to_string())?;
     let choices = [
         ("Color", "Color", "Modify/Generate/Install color tokens"),
+        (
+            "Typography",
+            "Typography",
+            "Generate/Install type scale tokens",
+        ),
+        (
+            "Layout",
+            "Layout",
+            "Generate/Install spacing, radius and shadow tokens",
+        ),
+        (
+            "Motion",
+            "Motion",
+            "Generate/Install duration, easing and animation tokens",
+        ),
         ("Go Back", "Go Back", "Return to the top menu"),
     ];
     let choice = select("What would you like to do?")
@@ -54,6 +67,15 @@ pub fn css_options() -> Result<()> {
         "Color" => {
             css::add_guided_palette()?;
         }
+        "Typography" => {
+            typography::add_guided_typography()?;
+        }
+        "Layout" => {
+            tokens::add_guided_tokens()?;
+        }
+        "Motion" => {
+            motion::add_guided_motion()?;
+        }
         "Go Back" => {
             guided_ui()?;
         }
@@ -64,13 +86,16 @@ pub fn css_options() -> Result<()> {
 
 pub fn install_atoms() -> Result<()> {
     intro("Installing all Atoms...".to_string())?;
-    let all_atoms = atoms::registry::Atoms::all_variants();
+    let plan = atoms::registry::Atoms::plan(&atoms::registry::Atoms::all_variants())?;
+    let npm_dependencies = plan.npm_dependencies();
+    plan.choose_options()?;
     let multi = multi_progress("Cloning...");
-    let pb1 = multi.add(progress_bar(all_atoms.len() as u64));
+    let pb1 = multi.add(progress_bar(plan.len() as u64));
     pb1.start("JSX...");
     let client_thread = thread::spawn(move || -> Result<()> {
-        for atom in all_atoms {
-            atom.install()?;
+        for step in plan.steps {
+            pb1.set_message(format!("Writing: {}", step.name()));
+            step.write()?;
             pb1.inc(1);
         }
         pb1.stop("Cloning Complete");
@@ -78,6 +103,7 @@ pub fn install_atoms() -> Result<()> {
         Ok(())
     });
     client_thread.join().unwrap()?;
+    writes::npm::check_if_deps_installed(&npm_dependencies)?;
     outro("Atoms complete!")?;
     Ok(())
 }
@@ -1,3 +1,4 @@
 pub mod named_palettes;
+pub mod reads;
 pub mod transforms;
 pub mod writes;
@@ -0,0 +1,175 @@
+use super::transforms::{AccentHexes, DevanoPalette, NeutralHexes};
+use super::writes::palette_css_path;
+use anyhow::{Result, anyhow};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+
+const DARK_MODE_QUERY: &str = "@media (prefers-color-scheme: dark)";
+
+/// Reads the project's `palette.css` (see `writes::palette_css_path`) back into a `DevanoPalette`.
+pub fn read_devano_palette_css() -> Result<DevanoPalette> {
+    let file_path = palette_css_path();
+    let css = fs::read_to_string(&file_path)
+        .map_err(|e| anyhow!("Could not read {:?}: {}", file_path, e))?;
+    parse_devano_palette_css(&css)
+}
+
+/// Parses the format produced by `write_devano_palette_css`.
+///
+/// The light `:root` block holds `bg` (aleva), `fg` (kora) and the `ona`/`uvo`/`bala` accents,
+/// the dark block swaps the neutrals and holds the `ara`/`ene`/`izi` accents.
+/// Because a `DevanoPalette` only stores each neutral once, the dark neutrals must mirror
+/// the light ones - a hand-tweak that breaks the mirror is reported instead of silently dropped.
+pub fn parse_devano_palette_css(css: &str) -> Result<DevanoPalette> {
+    let (light_css, dark_css) = css
+        .split_once(DARK_MODE_QUERY)
+        .ok_or_else(|| anyhow!("No `{}` block found", DARK_MODE_QUERY))?;
+    let light = collect_variables(light_css);
+    let dark = collect_variables(dark_css);
+
+    let aleva = read_neutrals(&light, "bg", ":root")?;
+    let kora = read_neutrals(&light, "fg", ":root")?;
+    check_mirror(&kora, &read_neutrals(&dark, "bg", "dark")?, "bg", "fg")?;
+    check_mirror(&aleva, &read_neutrals(&dark, "fg", "dark")?, "fg", "bg")?;
+
+    Ok(DevanoPalette {
+        kora,
+        aleva,
+        ara: read_accents(&dark, "c-a", "dark")?,
+        ene: read_accents(&dark, "c-e", "dark")?,
+        izi: read_accents(&dark, "c-i", "dark")?,
+        ona: read_accents(&light, "c-a", ":root")?,
+        uvo: read_accents(&light, "c-e", ":root")?,
+        bala: read_accents(&light, "c-i", ":root")?,
+    })
+}
+
+fn collect_variables(css: &str) -> HashMap<String, String> {
+    let declaration =
+        Regex::new(r"--([a-zA-Z0-9-]+)\s*:\s*([^;]+);").expect("Failed to compile regex");
+    declaration
+        .captures_iter(css)
+        .map(|caps| (caps[1].to_string(), caps[2].trim().to_string()))
+        .collect()
+}
+
+fn read_hex(variables: &HashMap<String, String>, name: &str, block: &str) -> Result<String> {
+    let hex_regex = Regex::new(r"^#(?:[0-9a-fA-F]{3}){1,2}$").expect("Failed to compile regex");
+    let value = variables
+        .get(name)
+        .ok_or_else(|| anyhow!("Missing --{} in the {} block", name, block))?;
+    if !hex_regex.is_match(value) {
+        return Err(anyhow!(
+            "--{} in the {} block is `{}`, expected a hex-code",
+            name,
+            block,
+            value
+        ));
+    }
+    Ok(value.clone())
+}
+
+fn read_neutrals(
+    variables: &HashMap<String, String>,
+    prefix: &str,
+    block: &str,
+) -> Result<NeutralHexes> {
+    Ok(NeutralHexes {
+        ara: read_hex(variables, &format!("{}-a", prefix), block)?,
+        ene: read_hex(variables, &format!("{}-e", prefix), block)?,
+        izi: read_hex(variables, &format!("{}-i", prefix), block)?,
+        ona: read_hex(variables, &format!("{}-o", prefix), block)?,
+    })
+}
+
+fn read_accents(
+    variables: &HashMap<String, String>,
+    prefix: &str,
+    block: &str,
+) -> Result<AccentHexes> {
+    Ok(AccentHexes {
+        ara: read_hex(variables, &format!("{}-a", prefix), block)?,
+        ene: read_hex(variables, &format!("{}-e", prefix), block)?,
+        izi: read_hex(variables, &format!("{}-i", prefix), block)?,
+    })
+}
+
+fn check_mirror(
+    light: &NeutralHexes,
+    dark: &NeutralHexes,
+    dark_prefix: &str,
+    light_prefix: &str,
+) -> Result<()> {
+    let pairs = [
+        ("a", &light.ara, &dark.ara),
+        ("e", &light.ene, &dark.ene),
+        ("i", &light.izi, &dark.izi),
+        ("o", &light.ona, &dark.ona),
+    ];
+    for (step, light_hex, dark_hex) in pairs {
+        if !light_hex.eq_ignore_ascii_case(dark_hex) {
+            return Err(anyhow!(
+                "Dark --{}-{} ({}) no longer mirrors light --{}-{} ({})",
+                dark_prefix,
+                step,
+                dark_hex,
+                light_prefix,
+                step,
+                light_hex
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::client::colors::named_palettes::NamedPalette;
+    use crate::library::client::colors::writes::devano_palette_css;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn round_trips_every_named_palette() {
+        for named in NamedPalette::iter() {
+            let palette = named.get_palette();
+            let parsed = parse_devano_palette_css(&devano_palette_css(&palette))
+                .unwrap_or_else(|e| panic!("{:?}: {}", named, e));
+            assert_eq!(parsed, palette, "{:?}", named);
+        }
+    }
+
+    #[test]
+    fn rejects_a_missing_dark_block() {
+        let css = devano_palette_css(&NamedPalette::Default.get_palette());
+        let (light_only, _) = css.split_once(DARK_MODE_QUERY).unwrap();
+        let error = parse_devano_palette_css(light_only).unwrap_err();
+        assert!(error.to_string().contains("No `@media"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_a_broken_neutral_mirror() {
+        let palette = NamedPalette::Default.get_palette();
+        let css = devano_palette_css(&palette);
+        let (light, dark) = css.split_once(DARK_MODE_QUERY).unwrap();
+        let tweaked = format!(
+            "{}{}{}",
+            light,
+            DARK_MODE_QUERY,
+            dark.replacen(
+                &format!("--bg-e: {};", palette.kora.ene),
+                "--bg-e: #123456;",
+                1
+            )
+        );
+        let error = parse_devano_palette_css(&tweaked).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Dark --bg-e (#123456) no longer mirrors light --fg-e ({})",
+                palette.kora.ene
+            )
+        );
+    }
+}
@@ -12,7 +12,7 @@ impl fmt::Display for PaletteError {
 
 impl std::error::Error for PaletteError {}
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DevanoPalette {
     pub kora: NeutralHexes,
     pub aleva: NeutralHexes,
@@ -24,7 +24,7 @@ pub struct DevanoPalette {
     pub bala: AccentHexes,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct NeutralHexes {
     pub ara: String,
     pub ene: String,
@@ -32,13 +32,27 @@ pub struct NeutralHexes {
     pub ona: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccentHexes {
     pub ara: String,
     pub ene: String,
     pub izi: String,
 }
 
+impl NeutralHexes {
+    /// The four tones in token order (`ara`, `ene`, `izi`, `ona`).
+    pub fn hexes(&self) -> [&str; 4] {
+        [&self.ara, &self.ene, &self.izi, &self.ona]
+    }
+}
+
+impl AccentHexes {
+    /// The three shades in token order (`ara`, `ene`, `izi`).
+    pub fn hexes(&self) -> [&str; 3] {
+        [&self.ara, &self.ene, &self.izi]
+    }
+}
+
 pub fn make_simple_devano_palette(hex_color: &str) -> Result<DevanoPalette, PaletteError> {
     let rgb = parse_hex(hex_color);
     let main_oklch: Oklch = rgb.into_color();
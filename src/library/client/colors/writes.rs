@@ -1,7 +1,7 @@
 use super::transforms;
 use anyhow::Result;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub fn write_devano_palette_rust(palette: &transforms::DevanoPalette) -> Result<()> {
     // this function is for use in the Devano CLI source code - it outputs a full palette definition to a rust file
@@ -89,13 +89,19 @@ pub fn write_devano_palette_rust(palette: &transforms::DevanoPalette) -> Result<
     Ok(())
 }
 
-pub fn write_devano_palette_css(palette: &transforms::DevanoPalette) -> Result<()> {
-    // prep the file
-    let dir_path = Path::new("src").join("css").join("devano");
-    fs::create_dir_all(&dir_path)?;
-    let file_name = format!("palette.css");
-    let file_path = dir_path.join(&file_name);
+/// Every generated stylesheet - palette, typography, tokens, motion - goes in this one
+/// folder, so a project can import them all from the same place.
+pub fn devano_css_dir() -> PathBuf {
+    Path::new("src").join("css").join("devano")
+}
+
+/// Where `write_devano_palette_css` puts the palette, and where `reads` looks for it.
+pub fn palette_css_path() -> PathBuf {
+    devano_css_dir().join("palette.css")
+}
 
+/// Renders the light `:root` block and the `prefers-color-scheme: dark` block for a palette.
+pub fn devano_palette_css(palette: &transforms::DevanoPalette) -> String {
     // Helper function to generate CSS variables for a given set of colors
     fn generate_neutrals(prefix: &str, colors: &transforms::NeutralHexes) -> Vec<String> {
         vec![
@@ -173,10 +179,18 @@ pub fn write_devano_palette_css(palette: &transforms::DevanoPalette) -> Result<(
     css_lines.push("\t}\n}".to_string());
 
     // Join all lines into a single CSS string
-    let css = css_lines.join("");
+    css_lines.join("")
+}
+
+pub fn write_devano_palette_css(palette: &transforms::DevanoPalette) -> Result<()> {
+    // prep the file
+    let file_path = palette_css_path();
+    if let Some(dir_path) = file_path.parent() {
+        fs::create_dir_all(dir_path)?;
+    }
 
     // Write the CSS to the file
-    fs::write(&file_path, css)?;
+    fs::write(&file_path, devano_palette_css(palette))?;
 
     println!("Palette written to {:?}", file_path);
     Ok(())
@@ -0,0 +1,38 @@
+use crate::library::client::templates::{ACCORDION, AVATAR, BADGE, PAGINATION, TABLE, TABS};
+use crate::library::client::utils::utils::UiComponent;
+use strum_macros::EnumIter;
+
+/// Atoms for showing collections and small pieces of data - tables and the pagination that
+/// drives them, tabs, accordions, badges and avatars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum DataDisplays {
+    Table,
+    Pagination,
+    Tabs,
+    Accordion,
+    Badge,
+    Avatar,
+}
+
+/// Retrieves the UI component associated with the data display type.
+///
+/// # Returns
+/// A reference to a static `UiComponent` that corresponds to the data display type.
+///
+/// # Example
+/// ```rust
+/// let data_display = DataDisplays::Table;
+/// let ui_component = data_display.get_ui();
+/// ```
+impl DataDisplays {
+    pub fn get_ui(&self) -> &'static UiComponent {
+        match &self {
+            DataDisplays::Table => &TABLE,
+            DataDisplays::Pagination => &PAGINATION,
+            DataDisplays::Tabs => &TABS,
+            DataDisplays::Accordion => &ACCORDION,
+            DataDisplays::Badge => &BADGE,
+            DataDisplays::Avatar => &AVATAR,
+        }
+    }
+}
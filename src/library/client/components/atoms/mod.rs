@@ -1,8 +1,11 @@
 pub mod buttons;
+pub mod data_display;
 pub mod decorators;
 pub mod feedback;
 pub mod icons;
 pub mod inputs;
 pub mod layout;
+pub mod navigation;
+pub mod overlays;
 pub mod registry;
 pub mod utils;
@@ -0,0 +1,33 @@
+use crate::library::client::templates::{BREADCRUMBS, FOOTER, NAVBAR, SIDEBAR};
+use crate::library::client::utils::utils::UiComponent;
+use strum_macros::EnumIter;
+
+/// Site chrome built on `@solidjs/router`'s `A`, so the current route is highlighted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum Navigations {
+    Navbar,
+    Sidebar,
+    Breadcrumbs,
+    Footer,
+}
+
+/// Retrieves the UI component associated with the navigation type.
+///
+/// # Returns
+/// A reference to a static `UiComponent` that corresponds to the navigation type.
+///
+/// # Example
+/// ```rust
+/// let navigation = Navigations::Navbar;
+/// let ui_component = navigation.get_ui();
+/// ```
+impl Navigations {
+    pub fn get_ui(&self) -> &'static UiComponent {
+        match &self {
+            Navigations::Navbar => &NAVBAR,
+            Navigations::Sidebar => &SIDEBAR,
+            Navigations::Breadcrumbs => &BREADCRUMBS,
+            Navigations::Footer => &FOOTER,
+        }
+    }
+}
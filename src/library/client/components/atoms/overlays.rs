@@ -0,0 +1,34 @@
+use crate::library::client::templates::{DRAWER, DROPDOWN_MENU, POPOVER, TOOLTIP};
+use crate::library::client::utils::utils::UiComponent;
+use strum_macros::EnumIter;
+
+/// Floating UI rendered in a portal. Every overlay shares `utils/overlay.ts` for
+/// Escape/click-outside dismissal, focus trapping and positioning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum Overlays {
+    Popover,
+    Drawer,
+    Tooltip,
+    DropdownMenu,
+}
+
+/// Retrieves the UI component associated with the overlay type.
+///
+/// # Returns
+/// A reference to a static `UiComponent` that corresponds to the overlay type.
+///
+/// # Example
+/// ```rust
+/// let overlay = Overlays::Drawer;
+/// let ui_component = overlay.get_ui();
+/// ```
+impl Overlays {
+    pub fn get_ui(&self) -> &'static UiComponent {
+        match &self {
+            Overlays::Popover => &POPOVER,
+            Overlays::Drawer => &DRAWER,
+            Overlays::Tooltip => &TOOLTIP,
+            Overlays::DropdownMenu => &DROPDOWN_MENU,
+        }
+    }
+}
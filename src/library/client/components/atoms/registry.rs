@@ -1,16 +1,21 @@
 use super::{
-    buttons::Buttons, decorators::Decorators, feedback::Feedbacks, icons::Icons, inputs::Inputs,
-    layout::Layouts, utils::Utils,
+    buttons::Buttons, data_display::DataDisplays, decorators::Decorators, feedback::Feedbacks,
+    icons::Icons, inputs::Inputs, layout::Layouts, navigation::Navigations, overlays::Overlays,
+    utils::Utils,
 };
-use crate::library::client::{utils, writes};
+use crate::library::client::components::options;
+use crate::library::client::components::plan::{InstallPlan, InstallStep};
+use crate::library::client::utils;
 use anyhow::Result;
-use std::fs;
 use std::path::Path;
+use strum::IntoEnumIterator;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Atoms {
     /// A collection of button components.
     Buttons(Buttons),
+    /// Tables, tabs and other ways of presenting data.
+    DataDisplay(DataDisplays),
     /// A collection of decorators for modifying components.
     Decorators(Decorators),
     /// Feedback information encapsulated in `Feedbacks`.
@@ -21,6 +26,10 @@ pub enum Atoms {
     Inputs(Inputs),
     /// Layout configurations for structuring components.
     Layout(Layouts),
+    /// Navbars, sidebars and other site chrome.
+    Navigation(Navigations),
+    /// Popovers, drawers and other floating UI.
+    Overlays(Overlays),
     /// Utility functions and helpers.
     Utils(Utils),
 }
@@ -31,6 +40,12 @@ pub enum Atoms {
 ///
 /// # Methods
 ///
+/// ## `all_variants`, `from_name` and `descriptions`
+///
+/// Every atom, generated from each category's `EnumIter` - adding a variant to `Buttons`,
+/// `Inputs`, etc. is enough to register it. Atoms are looked up and listed by the `name`
+/// of their `UiComponent`.
+///
 /// ## `devano_dependencies`
 ///
 /// Returns a static slice of `Atoms` representing the dependencies required by the current
@@ -47,14 +62,17 @@ pub enum Atoms {
 /// - **Returns:**  
 ///   A reference to a `UiComponent` instance.
 ///
+/// ## `write`
+///
+/// Writes the current `Atoms` instance to `client/src/devano`. Dependencies are not
+/// written - an `InstallPlan` orders them ahead of the atoms that need them.
+///
 /// ## `install`
 ///
 /// Installs the current `Atoms` instance by performing the following steps:
-/// 1. Resolves the base path for the component installation.
-/// 2. Retrieves the associated `UiComponent` details.
-/// 3. Recursively installs all dependencies of the current `Atoms` instance.
-/// 4. Ensures that required npm dependencies are installed.
-/// 5. Writes the component's contents to the appropriate file path.
+/// 1. Plans the atom and its dependency closure, each atom once, dependencies first.
+/// 2. Writes every planned atom (see `write`).
+/// 3. Ensures the npm dependencies of the whole plan are installed in the client package.
 ///
 /// - **Returns:**  
 ///   A `Result` indicating success or failure of the installation process.
@@ -63,28 +81,35 @@ pub enum Atoms {
 ///   This method propagates errors that may occur during dependency installation,
 ///   npm dependency checks, or file writing operations.
 impl Atoms {
-    pub fn all_variants() -> &'static [Atoms] {
-        &[
-            Atoms::Buttons(Buttons::Anchor),
-            Atoms::Buttons(Buttons::AnchorButton),
-            Atoms::Buttons(Buttons::Button),
-            Atoms::Buttons(Buttons::ButtonAnchor),
-            Atoms::Buttons(Buttons::IconButton),
-            Atoms::Decorators(Decorators::Separators),
-            Atoms::Feedback(Feedbacks::ErrorMsg),
-            Atoms::Icons(Icons::EyeClosed),
-            Atoms::Icons(Icons::EyeOpen),
-            Atoms::Inputs(Inputs::Text),
-            Atoms::Inputs(Inputs::Password),
-            Atoms::Inputs(Inputs::Otp),
-            Atoms::Layout(Layouts::Card),
-            Atoms::Layout(Layouts::Page),
-            Atoms::Layout(Layouts::PageInner),
-            Atoms::Layout(Layouts::Stack),
-            Atoms::Layout(Layouts::Heading),
-            Atoms::Layout(Layouts::Modal),
-            Atoms::Utils(Utils::Cn),
-        ]
+    /// Every atom, in category order, iterated from each category's variants.
+    pub fn all_variants() -> Vec<Atoms> {
+        Buttons::iter()
+            .map(Atoms::Buttons)
+            .chain(DataDisplays::iter().map(Atoms::DataDisplay))
+            .chain(Decorators::iter().map(Atoms::Decorators))
+            .chain(Feedbacks::iter().map(Atoms::Feedback))
+            .chain(Icons::iter().map(Atoms::Icons))
+            .chain(Inputs::iter().map(Atoms::Inputs))
+            .chain(Layouts::iter().map(Atoms::Layout))
+            .chain(Navigations::iter().map(Atoms::Navigation))
+            .chain(Overlays::iter().map(Atoms::Overlays))
+            .chain(Utils::iter().map(Atoms::Utils))
+            .collect()
+    }
+
+    /// Looks an atom up by its `UiComponent` name, e.g. `password-input`.
+    pub fn from_name(name: &str) -> Option<Atoms> {
+        Atoms::all_variants()
+            .into_iter()
+            .find(|atom| atom.get_ui().name == name)
+    }
+
+    /// `(name, description)` for every atom, for listings and prompts.
+    pub fn descriptions() -> Vec<(&'static str, &'static str)> {
+        Atoms::all_variants()
+            .iter()
+            .map(|atom| (atom.get_ui().name, atom.get_ui().description))
+            .collect()
     }
 
     pub fn devano_dependencies(&self) -> &'static [Atoms] {
@@ -92,19 +117,35 @@ impl Atoms {
             Atoms::Buttons(button) => match button {
                 Buttons::Anchor => &[Atoms::Utils(Utils::Cn)],
                 Buttons::AnchorButton => &[Atoms::Utils(Utils::Cn)],
-                Buttons::Button => &[Atoms::Utils(Utils::Cn)],
+                Buttons::Button => &[Atoms::Utils(Utils::Cn), Atoms::Feedback(Feedbacks::Spinner)],
                 Buttons::ButtonAnchor => &[Atoms::Utils(Utils::Cn)],
                 Buttons::IconButton => &[Atoms::Utils(Utils::Cn)],
             },
+            Atoms::DataDisplay(data) => match data {
+                DataDisplays::Table => &[
+                    Atoms::Utils(Utils::Cn),
+                    Atoms::DataDisplay(DataDisplays::Pagination),
+                ],
+                DataDisplays::Pagination => &[Atoms::Utils(Utils::Cn)],
+                DataDisplays::Tabs => &[Atoms::Utils(Utils::Cn)],
+                DataDisplays::Accordion => &[Atoms::Utils(Utils::Cn)],
+                DataDisplays::Badge => &[Atoms::Utils(Utils::Cn)],
+                DataDisplays::Avatar => &[Atoms::Utils(Utils::Cn)],
+            },
             Atoms::Decorators(decorator) => match decorator {
                 Decorators::Separators => &[Atoms::Utils(Utils::Cn)],
             },
             Atoms::Feedback(feedback) => match feedback {
                 Feedbacks::ErrorMsg => &[Atoms::Utils(Utils::Cn)],
+                Feedbacks::Toast => &[Atoms::Utils(Utils::Cn)],
+                Feedbacks::Spinner => &[Atoms::Utils(Utils::Cn)],
+                Feedbacks::Skeleton => &[Atoms::Utils(Utils::Cn)],
+                Feedbacks::Progress => &[Atoms::Utils(Utils::Cn)],
             },
             Atoms::Icons(icon) => match icon {
-                Icons::EyeClosed => &[Atoms::Utils(Utils::Cn)],
-                Icons::EyeOpen => &[Atoms::Utils(Utils::Cn)],
+                Icons::Icon => &[Atoms::Utils(Utils::Cn)],
+                // every other icon is generated as a wrapper around `Icon`
+                _ => &[Atoms::Icons(Icons::Icon)],
             },
             Atoms::Inputs(input) => match input {
                 Inputs::Text => &[Atoms::Utils(Utils::Cn)],
@@ -115,6 +156,16 @@ impl Atoms {
                     Atoms::Buttons(Buttons::IconButton),
                 ],
                 Inputs::Otp => &[Atoms::Utils(Utils::Cn)],
+                Inputs::Select => &[Atoms::Utils(Utils::Cn)],
+                Inputs::Checkbox => &[Atoms::Utils(Utils::Cn)],
+                Inputs::RadioGroup => &[Atoms::Utils(Utils::Cn)],
+                Inputs::Switch => &[Atoms::Utils(Utils::Cn)],
+                Inputs::Textarea => &[Atoms::Utils(Utils::Cn)],
+                Inputs::Form => &[
+                    Atoms::Utils(Utils::Cn),
+                    Atoms::Inputs(Inputs::Text),
+                    Atoms::Inputs(Inputs::Password),
+                ],
             },
             Atoms::Layout(layout) => match layout {
                 Layouts::Card => &[Atoms::Utils(Utils::Cn)],
@@ -122,10 +173,28 @@ impl Atoms {
                 Layouts::PageInner => &[Atoms::Utils(Utils::Cn)],
                 Layouts::Stack => &[Atoms::Utils(Utils::Cn)],
                 Layouts::Heading => &[Atoms::Utils(Utils::Cn)],
-                Layouts::Modal => &[],
+                Layouts::Modal => &[Atoms::Layout(Layouts::Card), Atoms::Utils(Utils::Overlay)],
+                Layouts::EmptyState => &[Atoms::Utils(Utils::Cn)],
+            },
+            Atoms::Navigation(navigation) => match navigation {
+                Navigations::Navbar => &[
+                    Atoms::Utils(Utils::Cn),
+                    Atoms::Buttons(Buttons::IconButton),
+                    Atoms::Overlays(Overlays::Drawer),
+                ],
+                Navigations::Sidebar => &[Atoms::Utils(Utils::Cn)],
+                Navigations::Breadcrumbs => &[Atoms::Utils(Utils::Cn)],
+                Navigations::Footer => &[Atoms::Utils(Utils::Cn)],
+            },
+            Atoms::Overlays(overlay) => match overlay {
+                Overlays::Popover => &[Atoms::Utils(Utils::Cn), Atoms::Utils(Utils::Overlay)],
+                Overlays::Drawer => &[Atoms::Utils(Utils::Cn), Atoms::Utils(Utils::Overlay)],
+                Overlays::Tooltip => &[Atoms::Utils(Utils::Cn), Atoms::Utils(Utils::Overlay)],
+                Overlays::DropdownMenu => &[Atoms::Utils(Utils::Cn), Atoms::Utils(Utils::Overlay)],
             },
             Atoms::Utils(util) => match util {
                 Utils::Cn => &[],
+                Utils::Overlay => &[],
             },
         }
     }
@@ -133,36 +202,64 @@ impl Atoms {
     pub fn get_ui(&self) -> &'static utils::utils::UiComponent {
         match self {
             Atoms::Buttons(button) => button.get_ui(),
+            Atoms::DataDisplay(data) => data.get_ui(),
             Atoms::Decorators(decorators) => decorators.get_ui(),
             Atoms::Feedback(feedbacks) => feedbacks.get_ui(),
             Atoms::Icons(icon) => icon.get_ui(),
             Atoms::Inputs(input) => input.get_ui(),
             Atoms::Layout(layouts) => layouts.get_ui(),
+            Atoms::Navigation(navigation) => navigation.get_ui(),
+            Atoms::Overlays(overlay) => overlay.get_ui(),
             Atoms::Utils(utils) => utils.get_ui(),
         }
     }
 
-    pub fn install(&self) -> Result<()> {
+    pub fn write(&self) -> Result<()> {
         let base_path = Path::new("client/src/devano");
         let ui_component = self.get_ui();
         let component_path = base_path.join(ui_component.folder_path); // Use folder_path
-
-        // Recursively install dependencies
-        for dependency in self.devano_dependencies() {
-            dependency.install()?; // Recursively call `install` for each dependency
-        }
-
         let file_path = component_path.join(ui_component.filename); // Chain folder_path and filename
 
-        utils::utils::write_file(&file_path, ui_component.contents)?;
+        utils::utils::write_file(&file_path, &options::rendered_contents(ui_component)?)?;
 
         Ok(())
     }
 
+    /// The install plan for a batch of atoms and everything they depend on.
+    pub fn plan(atoms: &[Atoms]) -> Result<InstallPlan> {
+        let roots: Vec<InstallStep> = atoms.iter().map(|atom| InstallStep::Atom(*atom)).collect();
+        InstallPlan::new(&roots)
+    }
+
+    pub fn install(&self) -> Result<()> {
+        Atoms::plan(&[*self])?.install()
+    }
+
     pub fn install_all() -> Result<()> {
+        Atoms::plan(&Atoms::all_variants())?.install()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    #[test]
+    fn every_atom_has_a_unique_install_path() {
+        let mut paths: HashSet<PathBuf> = HashSet::new();
         for atom in Atoms::all_variants() {
-            atom.install()?;
+            let ui = atom.get_ui();
+            let path = Path::new(ui.folder_path).join(ui.filename);
+            assert!(paths.insert(path.clone()), "{:?} is installed twice", path);
+        }
+    }
+
+    #[test]
+    fn every_atom_can_be_found_by_name() {
+        for atom in Atoms::all_variants() {
+            assert_eq!(Atoms::from_name(atom.get_ui().name), Some(atom));
         }
-        Ok(())
     }
 }
@@ -1,8 +1,11 @@
+use crate::library::client::templates::{CN, OVERLAY};
 use crate::library::client::utils::utils::UiComponent;
+use strum_macros::EnumIter;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
 pub enum Utils {
     Cn,
+    Overlay,
 }
 
 /// Provides utility methods for working with UI components.
@@ -18,26 +21,7 @@ impl Utils {
     pub fn get_ui(&self) -> &'static UiComponent {
         match &self {
             Utils::Cn => &CN,
+            Utils::Overlay => &OVERLAY,
         }
     }
 }
-
-pub const CN_LIT: &str = r#"
-import { twMerge } from "tailwind-merge";
-import clsx from "clsx";
-import type { ClassValue } from "clsx";
-
-export const cn = (...inputs: ClassValue[]) => {
-	return twMerge(clsx(inputs));
-};
-"#;
-
-pub const CN: UiComponent = UiComponent {
-    name: "cn",
-    description: "ClassName utility",
-    long_description: "",
-    filename: "cn.ts",
-    folder_path: "utils",
-    contents: CN_LIT,
-    npm_deps: &["tailwind-merge", "clsx"],
-};
@@ -0,0 +1,410 @@
+use regex::Regex;
+use std::fmt;
+
+/// An accessibility regression in a template. `line` is 1-based.
+#[derive(Debug, PartialEq)]
+pub enum A11yProblem {
+    /// An `IconButton`, or a button/link whose only content is an icon, with no accessible name.
+    UnnamedIconButton { element: String, line: usize },
+    /// An `<input>`, `<select>` or `<textarea>` with no `<label>` pointing at it, around it,
+    /// or `aria-label` standing in for one.
+    UnlabelledField { element: String, line: usize },
+    /// A portal with a full-screen backdrop that isn't a `role="dialog"` with `aria-modal`.
+    ModalWithoutDialogRole,
+    /// An interactive element whose classes never say what it looks like focused.
+    MissingFocusStyle { element: String, line: usize },
+}
+
+impl fmt::Display for A11yProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            A11yProblem::UnnamedIconButton { element, line } => {
+                write!(
+                    f,
+                    "line {}: icon-only <{}> needs an aria-label",
+                    line, element
+                )
+            }
+            A11yProblem::UnlabelledField { element, line } => {
+                write!(f, "line {}: <{}> has no associated label", line, element)
+            }
+            A11yProblem::ModalWithoutDialogRole => {
+                write!(f, "modal needs role=\"dialog\" and aria-modal")
+            }
+            A11yProblem::MissingFocusStyle { element, line } => {
+                write!(f, "line {}: <{}> has no focus style", line, element)
+            }
+        }
+    }
+}
+
+/// A JSX element as written in the source - enough to check its attributes and content.
+struct Element<'a> {
+    name: &'a str,
+    start: usize,
+    /// Everything between the tag name and the closing `>` of the opening tag.
+    attributes: &'a str,
+    /// `None` for self-closing elements.
+    children: Option<&'a str>,
+}
+
+impl Element<'_> {
+    fn has_attribute(&self, attribute: &str) -> bool {
+        Regex::new(&format!(r"(?:^|\s){}\s*=", regex::escape(attribute)))
+            .expect("Failed to compile regex")
+            .is_match(self.attributes)
+    }
+
+    /// The raw value of an attribute, delimiters included - `"..."` or `{...}`.
+    fn attribute(&self, attribute: &str) -> Option<&str> {
+        let name = Regex::new(&format!(r"(?:^|\s){}\s*=\s*", regex::escape(attribute)))
+            .expect("Failed to compile regex");
+        let value_start = name.find(self.attributes)?.end();
+        let rest = &self.attributes[value_start..];
+        if let Some(quoted) = rest.strip_prefix('"') {
+            return quoted.find('"').map(|end| &rest[..end + 2]);
+        }
+        if rest.starts_with('{') {
+            let end = balanced_end(rest, 0)?;
+            return Some(&rest[..=end]);
+        }
+        None
+    }
+}
+
+/// The index of the `}` closing the `{` at `open`, skipping over string literals.
+fn balanced_end(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    for (index, c) in text[open..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'' | '`') => quote = Some(c),
+            (None, '{') => depth += 1,
+            (None, '}') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Every `<name ...>` element in `contents`, with its content up to the matching `</name>`.
+fn elements<'a>(contents: &'a str, name: &'a str) -> Vec<Element<'a>> {
+    let open =
+        Regex::new(&format!(r"<{}[\s/>]", regex::escape(name))).expect("Failed to compile regex");
+    let close = format!("</{}>", name);
+    let mut found = Vec::new();
+    for tag in open.find_iter(contents) {
+        let attributes_start = tag.start() + 1 + name.len();
+        // the opening tag ends at the first `>` outside braces and quotes
+        let mut depth = 0;
+        let mut quote: Option<char> = None;
+        let mut tag_end = None;
+        for (index, c) in contents[attributes_start..].char_indices() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'' | '`') if depth == 0 => quote = Some(c),
+                (None, '{') => depth += 1,
+                (None, '}') => depth -= 1,
+                (None, '>') if depth == 0 => {
+                    tag_end = Some(attributes_start + index);
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let Some(tag_end) = tag_end else {
+            continue;
+        };
+        let attributes = &contents[attributes_start..tag_end];
+        let children = if attributes.trim_end().ends_with('/') {
+            None
+        } else {
+            // the matching close tag, skipping any of the same element nested inside
+            let body_start = tag_end + 1;
+            let mut nested = 0;
+            let mut cursor = body_start;
+            let mut body_end = None;
+            while let Some(offset) = contents[cursor..].find(&close) {
+                let candidate = cursor + offset;
+                nested += open.find_iter(&contents[cursor..candidate]).count();
+                if nested == 0 {
+                    body_end = Some(candidate);
+                    break;
+                }
+                nested -= 1;
+                cursor = candidate + close.len();
+            }
+            body_end.map(|end| &contents[body_start..end])
+        };
+        found.push(Element {
+            name,
+            start: tag.start(),
+            attributes: attributes.trim_end_matches('/'),
+            children,
+        });
+    }
+    found
+}
+
+fn line_of(contents: &str, offset: usize) -> usize {
+    contents[..offset].matches('\n').count() + 1
+}
+
+/// The components a template imports from `atoms/icons`, plus plain `svg`.
+fn icon_names(contents: &str) -> Vec<String> {
+    let import = Regex::new(r#"import\s+(?:\{([^}]*)\}|(\w+))\s+from\s+"~/devano/atoms/icons/"#)
+        .expect("Failed to compile regex");
+    let mut names = vec!["svg".to_string()];
+    for caps in import.captures_iter(contents) {
+        let list = caps.get(1).or(caps.get(2)).map_or("", |m| m.as_str());
+        names.extend(
+            list.split(',')
+                .map(|name| name.trim().trim_start_matches("type ").to_string())
+                .filter(|name| !name.is_empty() && name != "IconProps"),
+        );
+    }
+    names
+}
+
+/// Content with every tag and `{expression}` removed - what a screen reader would read out.
+fn visible_text(children: &str) -> String {
+    let mut text = String::new();
+    let mut depth = 0;
+    let mut in_tag = false;
+    for c in children.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            '<' if depth == 0 => in_tag = true,
+            '>' if depth == 0 && in_tag => in_tag = false,
+            _ if depth == 0 && !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+fn is_named(element: &Element) -> bool {
+    ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|attribute| element.has_attribute(attribute))
+}
+
+fn icon_button_problems(contents: &str) -> Vec<A11yProblem> {
+    let icons = icon_names(contents);
+    let mut problems = Vec::new();
+    let mut check = |element: &Element, icon_only: bool| {
+        if icon_only && !is_named(element) {
+            problems.push(A11yProblem::UnnamedIconButton {
+                element: element.name.to_string(),
+                line: line_of(contents, element.start),
+            });
+        }
+    };
+    for element in elements(contents, "IconButton") {
+        check(&element, true);
+    }
+    for name in ["button", "a", "A"] {
+        for element in elements(contents, name) {
+            let icon_only = element.children.is_some_and(|children| {
+                visible_text(children).is_empty()
+                    && icons.iter().any(|icon| {
+                        children.contains(&format!("<{} ", icon))
+                            || children.contains(&format!("<{}>", icon))
+                            || children.contains(&format!("<{}\n", icon))
+                            || children.contains(&format!("<{}\t", icon))
+                    })
+            });
+            check(&element, icon_only);
+        }
+    }
+    problems
+}
+
+fn field_problems(contents: &str) -> Vec<A11yProblem> {
+    let has_for = Regex::new(r"(?:^|\s)(?:for|htmlFor)\s*=")
+        .expect("Failed to compile regex")
+        .is_match(contents);
+    let mut problems = Vec::new();
+    for name in ["input", "select", "textarea"] {
+        for element in elements(contents, name) {
+            if element.attribute("type") == Some("\"hidden\"") {
+                continue;
+            }
+            let before = &contents[..element.start];
+            let inside_label = before
+                .rfind("<label")
+                .is_some_and(|open| before.rfind("</label>").is_none_or(|close| close < open));
+            let labelled =
+                is_named(&element) || inside_label || (element.has_attribute("id") && has_for);
+            if !labelled {
+                problems.push(A11yProblem::UnlabelledField {
+                    element: name.to_string(),
+                    line: line_of(contents, element.start),
+                });
+            }
+        }
+    }
+    problems
+}
+
+fn modal_problems(contents: &str) -> Vec<A11yProblem> {
+    let backdrop = Regex::new(r"\binset-0\b").expect("Failed to compile regex");
+    let is_modal = elements(contents, "Portal").iter().any(|portal| {
+        portal
+            .children
+            .is_some_and(|children| backdrop.is_match(children))
+    });
+    let dialog = Regex::new(r#"role="(?:alert)?dialog""#).expect("Failed to compile regex");
+    if is_modal && !(dialog.is_match(contents) && contents.contains("aria-modal")) {
+        vec![A11yProblem::ModalWithoutDialogRole]
+    } else {
+        vec![]
+    }
+}
+
+/// A focus-prefixed class that shows something - `focus:outline-none` alone hides the ring.
+fn shows_focus(classes: &str) -> bool {
+    let focus =
+        Regex::new(r"(?:^|[^\w-])(?:focus|focus-visible|focus-within):([\w\[\]\-\(\)/.%#]+)")
+            .expect("Failed to compile regex");
+    focus.captures_iter(classes).any(|caps| {
+        !matches!(
+            &caps[1],
+            "outline-none" | "outline-hidden" | "outline-0" | "ring-0"
+        )
+    })
+}
+
+fn focus_problems(contents: &str) -> Vec<A11yProblem> {
+    // a field can leave its focus state to a wrapper (`has-[input:focus-within]:`,
+    // `focus-within:`) or a styled sibling (`peer-focus-visible:`)
+    let shown_elsewhere = Regex::new(r"has-\[[^\]]*:focus|focus-within:|peer-focus")
+        .expect("Failed to compile regex")
+        .is_match(contents);
+    let identifier = Regex::new(r"\b[a-zA-Z_]\w*\b").expect("Failed to compile regex");
+    let mut problems = Vec::new();
+    for name in ["button", "a", "A", "input", "select", "textarea", "summary"] {
+        for element in elements(contents, name) {
+            let Some(class) = element.attribute("class") else {
+                continue;
+            };
+            // `class={cn([itemCn, ...])}` - add what the variables it uses are built from
+            let mut classes = class.to_string();
+            if class.starts_with('{') {
+                for word in identifier.find_iter(class) {
+                    let declaration = Regex::new(&format!(
+                        r"(?s)(?:let|const)\s+{}\s*=(.*?);\s*\n",
+                        regex::escape(word.as_str())
+                    ))
+                    .expect("Failed to compile regex");
+                    if let Some(declared) = declaration.captures(contents) {
+                        classes.push_str(&declared[1]);
+                    }
+                }
+            }
+            // only props like `class={l.class}` - nothing of the template's own to check
+            if !classes.contains('"') {
+                continue;
+            }
+            let is_field = matches!(name, "input" | "select" | "textarea");
+            let focus_shown = shows_focus(&classes) || (is_field && shown_elsewhere);
+            if !focus_shown {
+                problems.push(A11yProblem::MissingFocusStyle {
+                    element: name.to_string(),
+                    line: line_of(contents, element.start),
+                });
+            }
+        }
+    }
+    problems
+}
+
+pub fn a11y_problems(contents: &str) -> Vec<A11yProblem> {
+    let mut problems = icon_button_problems(contents);
+    problems.extend(field_problems(contents));
+    problems.extend(modal_problems(contents));
+    problems.extend(focus_problems(contents));
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::registry_components;
+    use super::*;
+    use crate::library::client::components::options::render;
+    use std::collections::HashMap;
+
+    #[test]
+    fn flags_unnamed_icon_buttons() {
+        let contents = r#"import EyeOpen from "~/devano/atoms/icons/EyeOpen";
+<IconButton onClick={() => open()}>
+	<EyeOpen size={24} />
+</IconButton>
+<IconButton aria-label="Show password" onClick={() => open()}><EyeOpen /></IconButton>
+<button class="focus-visible:outline-[2px]"><EyeOpen /></button>
+<button class="focus-visible:outline-[2px]"><EyeOpen /> Show</button>"#;
+        assert_eq!(
+            a11y_problems(contents),
+            vec![
+                A11yProblem::UnnamedIconButton {
+                    element: "IconButton".to_string(),
+                    line: 2
+                },
+                A11yProblem::UnnamedIconButton {
+                    element: "button".to_string(),
+                    line: 6
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_unlabelled_fields_modals_and_missing_focus() {
+        let contents = r#"<label class="text-[14px]">Email</label>
+<input class="focus:outline-[1px]" />
+<label for={id}>Name</label>
+<input id={id} class="focus:outline-[1px]" />
+<label>Agree <input type="checkbox" class="focus-visible:outline-[1px]" /></label>
+<input type="hidden" name="token" />
+<input aria-label="Search" class="focus:outline-none" />
+<Portal>
+	<div class="fixed inset-0" />
+</Portal>"#;
+        assert_eq!(
+            a11y_problems(contents),
+            vec![
+                A11yProblem::UnlabelledField {
+                    element: "input".to_string(),
+                    line: 2
+                },
+                A11yProblem::ModalWithoutDialogRole,
+                A11yProblem::MissingFocusStyle {
+                    element: "input".to_string(),
+                    line: 7
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn every_template_is_accessible() {
+        let problems: Vec<String> = registry_components()
+            .into_iter()
+            .flat_map(|component| {
+                a11y_problems(&render(component, &HashMap::new()))
+                    .into_iter()
+                    .map(move |problem| format!("{}: {}", component.filename, problem))
+            })
+            .collect();
+        assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    }
+}
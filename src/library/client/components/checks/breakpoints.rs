@@ -0,0 +1,136 @@
+use crate::library::client::tokens::transforms::BREAKPOINTS;
+use regex::Regex;
+use std::fmt;
+
+/// The widest fixed width that still fits a 320px phone once the page gutters (16px a side)
+/// and a card's padding (24px a side) are taken out.
+const PHONE_CONTENT_WIDTH_PX: f32 = 240.0;
+
+/// Something in a template that only works at laptop widths.
+#[derive(Debug, PartialEq)]
+pub enum BreakpointProblem {
+    /// `w-[35ch]` - an unprefixed width too wide for a phone; make it `w-full` and move the
+    /// fixed width behind a breakpoint.
+    FixedWidth(String),
+    /// `grid-cols-3`, `flex-row` - a multi-column layout at every width, with no breakpoint
+    /// variant in the same class string to start from one column.
+    MissingVariant(String),
+}
+
+impl fmt::Display for BreakpointProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BreakpointProblem::FixedWidth(found) => {
+                write!(f, "`{}` is wider than a phone at every breakpoint", found)
+            }
+            BreakpointProblem::MissingVariant(found) => {
+                write!(f, "`{}` has no `sm:`/`md:` variant", found)
+            }
+        }
+    }
+}
+
+/// `sm:`, `md:` ... as declared in the tokens - `max-sm:` style variants count too.
+fn breakpoint_prefix() -> String {
+    let names: Vec<&str> = BREAKPOINTS.iter().map(|(name, _)| *name).collect();
+    format!(r"(?:max-)?(?:{}):", names.join("|"))
+}
+
+/// A CSS length in px, assuming the default 16px root font and a 0.5em `ch`.
+fn length_px(value: f32, unit: &str) -> f32 {
+    match unit {
+        "rem" | "em" => value * 16.0,
+        "ch" => value * 8.0,
+        _ => value,
+    }
+}
+
+/// Checks every double-quoted string as one class list, so a layout class is fine as long
+/// as the same list also says what happens at another breakpoint.
+pub fn breakpoint_problems(contents: &str) -> Vec<BreakpointProblem> {
+    let string = Regex::new(r#""([^"\n]*)""#).expect("Failed to compile regex");
+    let prefix = breakpoint_prefix();
+    let prefixed = |utility: &str| {
+        Regex::new(&format!(
+            r"(?:^|\s)(?:[\w-]+:)*{}(?:[\w-]+:)*{}",
+            prefix, utility
+        ))
+        .expect("Failed to compile regex")
+    };
+    let has_prefixed_columns = prefixed(r"grid-cols-");
+    let has_prefixed_direction = prefixed(r"flex-(?:row|col)");
+    let has_prefixed_width = prefixed(r"(?:min-)?w-");
+    // only bare widths - `hover:w-[...]` and the like are state, not layout
+    let fixed_width = Regex::new(r"(?:^|\s)((?:min-)?w-\[(\d+(?:\.\d+)?)(px|rem|em|ch)\])(?:\s|$)")
+        .expect("Failed to compile regex");
+    let columns =
+        Regex::new(r"(?:^|\s)(grid-cols-(\d+))(?:\s|$)").expect("Failed to compile regex");
+    let row = Regex::new(r"(?:^|\s)(flex-row)(?:\s|$)").expect("Failed to compile regex");
+
+    let mut problems = Vec::new();
+    for caps in string.captures_iter(contents) {
+        let classes = &caps[1];
+        if !has_prefixed_width.is_match(classes) {
+            for width in fixed_width.captures_iter(classes) {
+                let value: f32 = width[2].parse().unwrap_or(0.0);
+                if length_px(value, &width[3]) > PHONE_CONTENT_WIDTH_PX {
+                    problems.push(BreakpointProblem::FixedWidth(width[1].to_string()));
+                }
+            }
+        }
+        if !has_prefixed_columns.is_match(classes) {
+            for grid in columns.captures_iter(classes) {
+                if grid[2].parse::<u32>().unwrap_or(1) > 1 {
+                    problems.push(BreakpointProblem::MissingVariant(grid[1].to_string()));
+                }
+            }
+        }
+        if !has_prefixed_direction.is_match(classes) && row.is_match(classes) {
+            problems.push(BreakpointProblem::MissingVariant("flex-row".to_string()));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::registry_components;
+    use super::*;
+    use crate::library::client::components::options::render;
+    use std::collections::HashMap;
+
+    #[test]
+    fn flags_laptop_only_layouts() {
+        let contents = r#"
+            <div class="flex flex-col w-[35ch] gap-[12px]" />
+            <div class="w-full sm:w-[35ch]" />
+            <div class="min-w-[32px] w-[44px]" />
+            <div class="grid grid-cols-3 gap-[12px]" />
+            <div class="grid grid-cols-1 md:grid-cols-3" />
+            <div class="flex flex-row" />
+            <div class="flex flex-col sm:flex-row" />
+        "#;
+        assert_eq!(
+            breakpoint_problems(contents),
+            vec![
+                BreakpointProblem::FixedWidth("w-[35ch]".to_string()),
+                BreakpointProblem::MissingVariant("grid-cols-3".to_string()),
+                BreakpointProblem::MissingVariant("flex-row".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_template_works_on_a_phone() {
+        let mut problems: Vec<String> = registry_components()
+            .into_iter()
+            .flat_map(|component| {
+                breakpoint_problems(&render(component, &HashMap::new()))
+                    .into_iter()
+                    .map(move |problem| format!("{}: {}", component.filename, problem))
+            })
+            .collect();
+        problems.dedup();
+        assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    }
+}
@@ -0,0 +1,338 @@
+use crate::library::client::components::atoms::registry::Atoms;
+use crate::library::client::components::features::auth::api::AuthRoutes;
+use crate::library::client::components::features::auth::ui::AuthMolecules;
+use crate::library::client::components::plan::InstallStep;
+use crate::library::new::client;
+use crate::library::package_manager::Pnpm;
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use strum::IntoEnumIterator;
+
+/// A file some part of the CLI writes under `client/src/devano`.
+pub struct InstalledFile {
+    /// `None` for files `devano new` scaffolds, which every project already has.
+    pub owner: Option<InstallStep>,
+    /// Relative to `src/devano`, extension included.
+    pub path: PathBuf,
+    pub contents: &'static str,
+}
+
+pub fn installed_files() -> Vec<InstalledFile> {
+    let mut files: Vec<InstalledFile> = Atoms::all_variants()
+        .into_iter()
+        .map(InstallStep::Atom)
+        .chain(AuthMolecules::iter().map(InstallStep::Molecule))
+        .map(|step| {
+            let ui = match step {
+                InstallStep::Atom(atom) => atom.get_ui(),
+                InstallStep::Molecule(molecule) => molecule.get_ui(),
+                _ => unreachable!(),
+            };
+            InstalledFile {
+                owner: Some(step),
+                path: Path::new(ui.folder_path).join(ui.filename),
+                contents: ui.contents,
+            }
+        })
+        .collect();
+    for route in AuthRoutes::iter() {
+        let api = route.get_api();
+        files.push(InstalledFile {
+            owner: Some(InstallStep::Route(route)),
+            path: Path::new("api").join(api.folder_path).join(api.filename),
+            contents: api.contents,
+        });
+    }
+    let (scaffold, _) = client::make_files(&Pnpm);
+    for file in scaffold {
+        if let Some(devano_tree) = file.folder_tree.strip_prefix(&[".", "src", "devano"]) {
+            files.push(InstalledFile {
+                owner: None,
+                path: devano_tree.iter().collect::<PathBuf>().join(file.filename),
+                contents: file.contents,
+            });
+        }
+    }
+    files
+}
+
+/// One `import ... from "..."` statement pointing into `~/devano` or a sibling file.
+#[derive(Debug)]
+pub struct DevanoImport {
+    pub clause: String,
+    pub specifier: String,
+    /// The module path relative to `src/devano`, without an extension.
+    pub module: PathBuf,
+}
+
+pub fn devano_imports(file: &InstalledFile) -> Vec<DevanoImport> {
+    let import = Regex::new(r#"(?m)^import\s+(?:type\s+)?([^;]+?)\s+from\s+"([^"]+)""#)
+        .expect("Failed to compile regex");
+    import
+        .captures_iter(file.contents)
+        .filter_map(|caps| {
+            let specifier = caps[2].to_string();
+            let module = if let Some(rest) = specifier.strip_prefix("~/devano/") {
+                PathBuf::from(rest)
+            } else if specifier.starts_with('.') {
+                normalize(&file.path.parent()?.join(&specifier))
+            } else {
+                return None; // a package
+            };
+            Some(DevanoImport {
+                clause: caps[1].to_string(),
+                specifier,
+                module,
+            })
+        })
+        .collect()
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::CurDir => {}
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// The installed file a module path resolves to - `x/y` matches `x/y.tsx` or `x/y/index.ts`.
+pub fn resolve<'a>(module: &Path, files: &'a [InstalledFile]) -> Option<&'a InstalledFile> {
+    files.iter().find(|file| {
+        file.path.with_extension("") == module
+            || file.path.with_extension("") == module.join("index")
+    })
+}
+
+/// Names an import clause expects the target module to export, `default` for a default import.
+pub fn imported_names(clause: &str) -> Vec<String> {
+    let clause = clause.trim();
+    if clause.starts_with('*') {
+        return vec![];
+    }
+    let (default, named) = match clause.find('{') {
+        Some(open) => (
+            clause[..open].trim().trim_end_matches(',').trim(),
+            &clause[open + 1..clause.rfind('}').unwrap_or(clause.len())],
+        ),
+        None => (clause, ""),
+    };
+    let mut names = Vec::new();
+    if !default.is_empty() {
+        names.push("default".to_string());
+    }
+    for name in named.split(',') {
+        let name = name.trim().trim_start_matches("type ");
+        if let Some(exported) = name.split_whitespace().next() {
+            names.push(exported.to_string());
+        }
+    }
+    names
+}
+
+pub fn exports(contents: &str, name: &str) -> bool {
+    if name == "default" {
+        return contents.contains("export default");
+    }
+    let declaration = Regex::new(&format!(
+        r"export\s+(?:async\s+)?(?:const|let|function|class|enum|type|interface)\s+{}\b",
+        regex::escape(name)
+    ))
+    .expect("Failed to compile regex");
+    let list = Regex::new(&format!(r"export\s*\{{[^}}]*\b{}\b", regex::escape(name)))
+        .expect("Failed to compile regex");
+    declaration.is_match(contents) || list.is_match(contents)
+}
+
+/// Runtime exports of `solid-js`, `solid-js/web` and `solid-js/store` the templates reach for.
+const SOLID_IDENTIFIERS: &[&str] = &[
+    "batch",
+    "children",
+    "createContext",
+    "createEffect",
+    "createMemo",
+    "createResource",
+    "createSignal",
+    "createStore",
+    "createUniqueId",
+    "Dynamic",
+    "ErrorBoundary",
+    "For",
+    "Index",
+    "isServer",
+    "Match",
+    "mergeProps",
+    "on",
+    "onCleanup",
+    "onMount",
+    "Portal",
+    "Show",
+    "splitProps",
+    "Suspense",
+    "Switch",
+    "untrack",
+    "useContext",
+];
+
+/// Solid identifiers `contents` calls or renders without importing them from a `solid-js`
+/// module - a `ReferenceError` on first render.
+pub fn missing_solid_imports(contents: &str) -> Vec<&'static str> {
+    let import = Regex::new(r#"(?m)^import\s+([^;]+?)\s+from\s+"solid-js(?:/\w+)?""#)
+        .expect("Failed to compile regex");
+    let imported: Vec<String> = import
+        .captures_iter(contents)
+        .filter(|caps| !caps[1].starts_with("type "))
+        .flat_map(|caps| imported_names(&caps[1]))
+        .collect();
+    // doc comments mention `children` and friends in prose
+    let comment = Regex::new(r"(?s)/\*.*?\*/|(?:^|\s)//[^\n]*").expect("Failed to compile regex");
+    let code = comment.replace_all(contents, "");
+    SOLID_IDENTIFIERS
+        .iter()
+        .copied()
+        .filter(|name| !imported.iter().any(|imported| imported == name))
+        .filter(|name| {
+            let escaped = regex::escape(name);
+            // a call that isn't a method (`props.children(...)`), or a JSX tag
+            let used = Regex::new(&format!(
+                r"(?:^|[^\w.$]){}\s*\(|<{}[\s/>]",
+                escaped, escaped
+            ))
+            .expect("Failed to compile regex");
+            let declared = Regex::new(&format!(
+                r"(?:function|const|let|var|class)\s+{}\b",
+                escaped
+            ))
+            .expect("Failed to compile regex");
+            used.is_match(&code) && !declared.is_match(&code)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_import_clauses() {
+        assert_eq!(imported_names("AuthNav"), vec!["default"]);
+        assert_eq!(
+            imported_names("{ useAuth, ViewState as View }"),
+            vec!["useAuth", "ViewState"]
+        );
+        assert_eq!(
+            imported_names("Modal, { type ModalProps }"),
+            vec!["default", "ModalProps"]
+        );
+    }
+
+    #[test]
+    fn every_devano_import_resolves() {
+        let files = installed_files();
+        let mut problems = Vec::new();
+        for file in &files {
+            for import in devano_imports(file) {
+                let Some(target) = resolve(&import.module, &files) else {
+                    problems.push(format!(
+                        "{}: nothing installs \"{}\"",
+                        file.path.display(),
+                        import.specifier
+                    ));
+                    continue;
+                };
+                for name in imported_names(&import.clause) {
+                    if !exports(target.contents, &name) {
+                        problems.push(format!(
+                            "{}: \"{}\" has no `{}` export",
+                            file.path.display(),
+                            import.specifier,
+                            name
+                        ));
+                    }
+                }
+            }
+        }
+        assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    }
+
+    #[test]
+    fn finds_solid_identifiers_used_without_an_import() {
+        let contents = r#"import { JSX, Match, splitProps, Switch, createSignal } from "solid-js";
+import { Portal } from "solid-js/web";
+import type { Accessor } from "solid-js";
+export function Input(props) {
+	const [l] = splitProps(props, ["children"]);
+	const id = l.id ?? createUniqueId();
+	// children() of the Show below
+	onMount(() => l.children(id));
+	return <Portal><Show when={l.open}><Switch /></Show></Portal>;
+}"#;
+        assert_eq!(
+            missing_solid_imports(contents),
+            vec!["createUniqueId", "onMount", "Show"]
+        );
+    }
+
+    #[test]
+    fn every_solid_identifier_is_imported() {
+        let problems: Vec<String> = installed_files()
+            .iter()
+            .flat_map(|file| {
+                missing_solid_imports(file.contents)
+                    .into_iter()
+                    .map(move |name| format!("{}: `{}` isn't imported", file.path.display(), name))
+            })
+            .collect();
+        assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    }
+
+    #[test]
+    fn every_imported_component_is_a_declared_dependency() {
+        let files = installed_files();
+        let mut problems = Vec::new();
+        for file in &files {
+            let Some(owner) = file.owner else {
+                continue; // scaffolded files ship together
+            };
+            let declared = owner.dependencies();
+            for import in devano_imports(file) {
+                let provider = resolve(&import.module, &files).and_then(|target| target.owner);
+                if let Some(provider) = provider
+                    && provider != owner
+                    && !declared.contains(&provider)
+                {
+                    problems.push(format!(
+                        "{} imports {} without declaring it",
+                        owner.name(),
+                        provider.name()
+                    ));
+                }
+            }
+        }
+        assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    }
+
+    #[test]
+    fn no_two_components_write_the_same_file() {
+        let mut writers: HashMap<PathBuf, Vec<String>> = HashMap::new();
+        for file in installed_files() {
+            let writer = file
+                .owner
+                .map_or("devano new".to_string(), |owner| owner.name().to_string());
+            writers.entry(file.path).or_default().push(writer);
+        }
+        let clashes: Vec<String> = writers
+            .into_iter()
+            .filter(|(_, writers)| writers.len() > 1)
+            .map(|(path, writers)| format!("{}: {}", path.display(), writers.join(", ")))
+            .collect();
+        assert!(clashes.is_empty(), "\n{}", clashes.join("\n"));
+    }
+}
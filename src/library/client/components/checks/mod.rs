@@ -0,0 +1,19 @@
+//! Checks that run over every shipped template as part of `cargo test`.
+mod a11y;
+mod breakpoints;
+mod integrity;
+mod tokens;
+
+use super::atoms::registry::Atoms;
+use super::features::auth::ui::AuthMolecules;
+use crate::library::client::utils::utils::UiComponent;
+use strum::IntoEnumIterator;
+
+/// Every `UiComponent` the CLI can install, atoms first.
+pub fn registry_components() -> Vec<&'static UiComponent> {
+    Atoms::all_variants()
+        .iter()
+        .map(|atom| atom.get_ui())
+        .chain(AuthMolecules::iter().map(|molecule| molecule.get_ui()))
+        .collect()
+}
@@ -0,0 +1,158 @@
+use super::registry_components;
+use crate::library::client::colors::named_palettes::NamedPalette;
+use crate::library::client::colors::writes::devano_palette_css;
+use crate::library::client::motion::named_motions::NamedMotion;
+use crate::library::client::motion::writes::devano_motion_css;
+use crate::library::client::tokens::named_tokens::NamedTokenSet;
+use crate::library::client::tokens::writes::devano_tokens_css;
+use crate::library::client::typography::named_scales::NamedTypeScale;
+use crate::library::client::typography::writes::devano_typography_css;
+use regex::Regex;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Something in a template that doesn't line up with the tokens the CSS writers emit.
+#[derive(Debug, PartialEq)]
+pub enum TokenProblem {
+    /// `var(--x)` / `text-(--x)` where no writer declares `--x`.
+    Unknown(String),
+    /// `border-(c-e-i)` - a token name used without its leading `--`.
+    MissingDashes(String),
+    /// `border(--fg-i)` - the utility is missing the `-` before the parenthesis.
+    MissingHyphen(String),
+    /// `bg-[#fff]`, `text-red-500` - a color that bypasses the palette entirely.
+    HardCodedColor(String),
+}
+
+impl fmt::Display for TokenProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenProblem::Unknown(token) => write!(f, "unknown token --{}", token),
+            TokenProblem::MissingDashes(found) => write!(f, "`{}` is missing its `--`", found),
+            TokenProblem::MissingHyphen(found) => {
+                write!(f, "`{}` is missing the `-` before `(`", found)
+            }
+            TokenProblem::HardCodedColor(found) => {
+                write!(f, "`{}` bypasses the palette", found)
+            }
+        }
+    }
+}
+
+/// Every custom property the Devano CSS writers declare, using the default presets -
+/// the names don't change between presets, only the values do.
+pub fn known_tokens() -> HashSet<String> {
+    let palette = NamedPalette::Default.get_palette();
+    let css = [
+        devano_palette_css(&palette),
+        devano_typography_css(&NamedTypeScale::Default.get_scale()),
+        devano_tokens_css(&NamedTokenSet::Default.get_tokens(), &palette),
+        devano_motion_css(&NamedMotion::Default.get_motion()),
+    ]
+    .join("\n");
+    let declaration = Regex::new(r"--([a-zA-Z0-9-]+)\s*:").expect("Failed to compile regex");
+    declaration
+        .captures_iter(&css)
+        .map(|caps| caps[1].to_string())
+        .collect()
+}
+
+pub fn token_problems(contents: &str, known: &HashSet<String>) -> Vec<TokenProblem> {
+    // `var(--x)`, Tailwind's `text-(--x)` / `text-(color:--x)` and v3's `text-[--x]`
+    let reference = Regex::new(r"(?:\(|\[|:)--([a-zA-Z0-9-]+)").expect("Failed to compile regex");
+    let missing_dashes =
+        Regex::new(r"-\(((?:bg|fg|c)-[aeio](?:-[aeio])?)\)").expect("Failed to compile regex");
+    let missing_hyphen =
+        Regex::new(r"([a-z]+)\(--[a-zA-Z0-9-]+\)").expect("Failed to compile regex");
+    let hard_coded = Regex::new(
+        r"\b(?:bg|text|border|outline|ring|fill|stroke|from|to|via)-(?:\[#[0-9a-fA-F]{3,8}\]|\[rgba?\([^\]]*\]|(?:white|black)\b|(?:slate|gray|zinc|neutral|stone|red|orange|amber|yellow|lime|green|emerald|teal|cyan|sky|blue|indigo|violet|purple|fuchsia|pink|rose)-\d{2,3}\b)",
+    )
+    .expect("Failed to compile regex");
+
+    let mut problems = Vec::new();
+    for caps in reference.captures_iter(contents) {
+        let token = &caps[1];
+        if !token.starts_with("tw-") && !known.contains(token) {
+            problems.push(TokenProblem::Unknown(token.to_string()));
+        }
+    }
+    for caps in missing_dashes.captures_iter(contents) {
+        problems.push(TokenProblem::MissingDashes(caps[0].to_string()));
+    }
+    for caps in missing_hyphen.captures_iter(contents) {
+        if &caps[1] != "var" {
+            problems.push(TokenProblem::MissingHyphen(caps[0].to_string()));
+        }
+    }
+    for found in hard_coded.find_iter(contents) {
+        problems.push(TokenProblem::HardCodedColor(found.as_str().to_string()));
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::client::components::options::render;
+    use std::collections::HashMap;
+
+    #[test]
+    fn known_tokens_cover_every_writer() {
+        let known = known_tokens();
+        for token in [
+            "bg-a",
+            "fg-o",
+            "c-i-e",
+            "type-lg",
+            "space-md",
+            "elevation-2",
+            "motion-duration-fast",
+        ] {
+            assert!(known.contains(token), "missing --{}", token);
+        }
+    }
+
+    #[test]
+    fn flags_unknown_malformed_and_hard_coded_colors() {
+        let known = known_tokens();
+        let contents = r#"class="text-(--fg-e) border-(--gh-e) hover:border-(c-e-i) hover:border(--fg-i) bg-[#fff] text-red-500 style={{ color: "var(--c-a-a)" }}""#;
+        assert_eq!(
+            token_problems(contents, &known),
+            vec![
+                TokenProblem::Unknown("gh-e".to_string()),
+                TokenProblem::MissingDashes("-(c-e-i)".to_string()),
+                TokenProblem::MissingHyphen("border(--fg-i)".to_string()),
+                TokenProblem::HardCodedColor("bg-[#fff]".to_string()),
+                TokenProblem::HardCodedColor("text-red-500".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_template_uses_existing_tokens() {
+        let known = known_tokens();
+        let mut problems: Vec<String> = registry_components()
+            .into_iter()
+            .flat_map(|component| {
+                // The default rendering, plus one per install-time choice.
+                let mut renderings = vec![render(component, &HashMap::new())];
+                for option in component.options {
+                    for choice in option.choices {
+                        let choices =
+                            HashMap::from([(option.key.to_string(), choice.id.to_string())]);
+                        renderings.push(render(component, &choices));
+                    }
+                }
+                let known = &known;
+                renderings.into_iter().flat_map(move |contents| {
+                    token_problems(&contents, known)
+                        .into_iter()
+                        .map(move |problem| format!("{}: {}", component.filename, problem))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        problems.dedup();
+        assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    }
+}
@@ -1,18 +1,23 @@
 use crate::library::client::components::features::auth::api_partials::index;
 use crate::library::client::components::features::utils::Installable;
 use crate::library::client::components::features::writes;
-use crate::library::client::utils;
 use crate::library::client::utils::utils::ApiComponent;
 use anyhow::Result;
-use std::fs;
-use std::path::Path;
 use strum_macros::EnumIter;
 
-#[derive(Debug, Clone, Copy, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
 pub enum AuthRoutes {
     Index,
 }
 
+impl AuthRoutes {
+    pub fn get_api(&self) -> &'static ApiComponent {
+        match self {
+            AuthRoutes::Index => &index::INDEX,
+        }
+    }
+}
+
 /// Implements the `install` method for the `AuthRoutes` enum.
 ///
 /// # Returns
@@ -21,11 +26,8 @@ pub enum AuthRoutes {
 ///
 /// # Behavior
 ///
-/// This method matches on the variant of `AuthRoutes` and performs the corresponding
-/// installation logic:
-///
-/// - For `AuthRoutes::Index`, it calls `writes::add_api` with the `INDEX` constant
-///   and propagates any errors that may occur.
+/// This method calls `writes::add_api` with the route's `ApiComponent` (see `get_api`)
+/// and propagates any errors that may occur.
 ///
 /// # Errors
 ///
@@ -37,11 +39,7 @@ impl Installable for AuthRoutes {
         }
     }
     fn install(&self) -> Result<()> {
-        match self {
-            AuthRoutes::Index => {
-                writes::add_api(&index::INDEX)?;
-                Ok(())
-            }
-        }
+        writes::add_api(self.get_api())?;
+        Ok(())
     }
 }
@@ -1,4 +1,3 @@
 pub mod api;
 pub mod api_partials;
 pub mod ui;
-pub mod ui_partials;
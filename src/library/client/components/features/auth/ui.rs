@@ -1,21 +1,20 @@
 use crate::library::client::components::atoms::registry::Atoms;
 use crate::library::client::components::atoms::{
-    buttons::Buttons, decorators::Decorators, feedback::Feedbacks, icons::Icons, inputs::Inputs,
-    layout::Layouts, utils::Utils,
+    buttons::Buttons, decorators::Decorators, feedback::Feedbacks, inputs::Inputs, layout::Layouts,
 };
-use crate::library::client::components::features::auth::ui_partials::{
-    auth, auth_inner, auth_nav, auth_state, log_in_form, register_form,
+use crate::library::client::components::features::utils::Installable;
+use crate::library::client::components::options;
+use crate::library::client::templates::{
+    AUTH, AUTH_INNER, AUTH_NAV, AUTH_STATE, LOG_IN_FORM, PASSWORD_RESET_CHALLENGE,
+    PASSWORD_RESET_REQUEST, REGISTER_FORM,
 };
-use crate::library::client::components::features::utils::{Installable, MoleculeInstallable};
-use crate::library::client::utils::utils::{UiComponent, write_file};
-use crate::library::client::{utils, writes};
+use crate::library::client::utils;
+use crate::library::client::utils::utils::write_file;
 use anyhow::Result;
-use std::fs;
-use std::io::{self};
 use std::path::Path;
 use strum_macros::EnumIter;
 
-#[derive(Debug, Clone, Copy, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
 pub enum AuthMolecules {
     Auth,
     AuthState,
@@ -23,6 +22,8 @@ pub enum AuthMolecules {
     AuthNav,
     LogInForm,
     RegisterForm,
+    PasswordResetRequest,
+    PasswordResetChallenge,
 }
 
 /// Implementation of the `AuthMolecules` enum, providing methods for managing
@@ -35,27 +36,30 @@ pub enum AuthMolecules {
 /// required by the specific `AuthMolecules` variant. Each variant has its own set of
 /// dependencies, which may include buttons, inputs, or decorators.
 ///
+/// ## `molecule_dependencies`
+/// Returns the other `AuthMolecules` the variant imports, so the install plan writes them
+/// first.
+///
 /// ## `get_ui`
 /// Returns a static reference to a `UiComponent` associated with the specific
 /// `AuthMolecules` variant. This is used to retrieve the UI component's metadata,
 /// such as its folder path, filename, and contents.
 ///
 /// ## `install`
-/// Installs the UI component represented by the `AuthMolecules` variant. This includes:
-/// - Resolving the base path for the component.
-/// - Recursively installing all atom dependencies.
-/// - Checking if the required npm dependencies are installed.
-/// - Writing the component's file to the appropriate location.
+/// Installs the UI component represented by the `AuthMolecules` variant by writing the
+/// component's file to the appropriate location.
+///
+/// Atom dependencies and npm dependencies are left to `Features::install`, whose
+/// `InstallPlan` writes each atom once before the molecules that use it and installs the
+/// whole feature's npm dependencies with a single package-manager call.
 ///
 /// # Errors
-/// The `install` method returns a `Result` that may contain an error if:
-/// - A dependency installation fails.
-/// - Npm dependencies are not installed.
-/// - Writing the component file fails.
+/// The `install` method returns a `Result` that may contain an error if writing the
+/// component file fails.
 impl AuthMolecules {
     pub fn atom_dependencies(&self) -> &'static [Atoms] {
         match self {
-            AuthMolecules::Auth => &[],
+            AuthMolecules::Auth => &[Atoms::Feedback(Feedbacks::Toast)],
             AuthMolecules::AuthState => &[],
             AuthMolecules::AuthInner => &[],
             AuthMolecules::AuthNav => &[
@@ -64,29 +68,72 @@ impl AuthMolecules {
             ],
             AuthMolecules::LogInForm => &[
                 Atoms::Buttons(Buttons::Button),
-                Atoms::Inputs(Inputs::Text),
-                Atoms::Inputs(Inputs::Password),
+                Atoms::Inputs(Inputs::Form),
                 Atoms::Layout(Layouts::Card),
-                Atoms::Feedback(Feedbacks::ErrorMsg),
+                Atoms::Layout(Layouts::Heading),
+                Atoms::Feedback(Feedbacks::Toast),
                 Atoms::Decorators(Decorators::Separators),
             ],
             AuthMolecules::RegisterForm => &[
                 Atoms::Buttons(Buttons::Button),
-                Atoms::Inputs(Inputs::Text),
-                Atoms::Inputs(Inputs::Password),
+                Atoms::Inputs(Inputs::Form),
+                Atoms::Layout(Layouts::Card),
+                Atoms::Layout(Layouts::Heading),
+                Atoms::Feedback(Feedbacks::Toast),
+                Atoms::Decorators(Decorators::Separators),
+            ],
+            AuthMolecules::PasswordResetRequest => &[
+                Atoms::Buttons(Buttons::Button),
+                Atoms::Inputs(Inputs::Form),
+                Atoms::Layout(Layouts::Card),
+                Atoms::Layout(Layouts::Heading),
+                Atoms::Feedback(Feedbacks::Toast),
+                Atoms::Decorators(Decorators::Separators),
+            ],
+            AuthMolecules::PasswordResetChallenge => &[
+                Atoms::Inputs(Inputs::Otp),
+                Atoms::Layout(Layouts::Card),
+                Atoms::Layout(Layouts::Heading),
+                Atoms::Feedback(Feedbacks::ErrorMsg),
+                Atoms::Feedback(Feedbacks::Toast),
                 Atoms::Decorators(Decorators::Separators),
             ],
         }
     }
 
+    pub fn molecule_dependencies(&self) -> &'static [AuthMolecules] {
+        match self {
+            AuthMolecules::Auth => &[AuthMolecules::AuthState, AuthMolecules::AuthInner],
+            AuthMolecules::AuthState => &[],
+            AuthMolecules::AuthInner => &[
+                AuthMolecules::AuthState,
+                AuthMolecules::LogInForm,
+                AuthMolecules::RegisterForm,
+                AuthMolecules::PasswordResetRequest,
+                AuthMolecules::PasswordResetChallenge,
+            ],
+            AuthMolecules::AuthNav => &[AuthMolecules::AuthState],
+            AuthMolecules::LogInForm => &[AuthMolecules::AuthState, AuthMolecules::AuthNav],
+            AuthMolecules::RegisterForm => &[AuthMolecules::AuthState, AuthMolecules::AuthNav],
+            AuthMolecules::PasswordResetRequest => {
+                &[AuthMolecules::AuthState, AuthMolecules::AuthNav]
+            }
+            AuthMolecules::PasswordResetChallenge => {
+                &[AuthMolecules::AuthState, AuthMolecules::AuthNav]
+            }
+        }
+    }
+
     pub fn get_ui(&self) -> &'static utils::utils::UiComponent {
         match self {
-            AuthMolecules::Auth => &auth::AUTH,
-            AuthMolecules::AuthState => &auth_state::AUTH_STATE,
-            AuthMolecules::AuthInner => &auth_inner::AUTH_INNER,
-            AuthMolecules::AuthNav => &auth_nav::AUTH_NAV,
-            AuthMolecules::LogInForm => &log_in_form::LOG_IN_FORM,
-            AuthMolecules::RegisterForm => &register_form::REGISTER_FORM,
+            AuthMolecules::Auth => &AUTH,
+            AuthMolecules::AuthState => &AUTH_STATE,
+            AuthMolecules::AuthInner => &AUTH_INNER,
+            AuthMolecules::AuthNav => &AUTH_NAV,
+            AuthMolecules::LogInForm => &LOG_IN_FORM,
+            AuthMolecules::RegisterForm => &REGISTER_FORM,
+            AuthMolecules::PasswordResetRequest => &PASSWORD_RESET_REQUEST,
+            AuthMolecules::PasswordResetChallenge => &PASSWORD_RESET_CHALLENGE,
         }
     }
 }
@@ -101,18 +148,9 @@ impl Installable for AuthMolecules {
         let base_path = Path::new("client/src/devano");
         let ui_component = self.get_ui();
         let component_path = base_path.join(ui_component.folder_path); // Use folder_path
-
-        // Recursively install dependencies
-        for dependency in self.atom_dependencies() {
-            dependency.install()?; // Recursively call `install` for each dependency
-        }
-
-        // // Handle npm dependencies
-        writes::npm::check_if_deps_installed(ui_component.npm_deps)?;
-
         let file_path = component_path.join(ui_component.filename); // Chain folder_path and filename
 
-        write_file(&file_path, ui_component.contents)?;
+        write_file(&file_path, &options::rendered_contents(ui_component)?)?;
 
         Ok(())
     }
@@ -1,6 +0,0 @@
-pub mod auth;
-pub mod auth_inner;
-pub mod auth_nav;
-pub mod auth_state;
-pub mod log_in_form;
-pub mod register_form;
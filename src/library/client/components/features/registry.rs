@@ -1,15 +1,14 @@
-use crate::library::client::components::features::utils::Installable;
 use crate::library::client::components::features::{
     auth::api::AuthRoutes, auth::ui::AuthMolecules,
 };
-use crate::library::client::utils::utils;
+use crate::library::client::components::plan::{InstallPlan, InstallStep};
+use crate::library::client::writes;
 use anyhow::Result;
-use cliclack::{intro, multi_progress, outro, progress_bar};
-use std::thread;
+use cliclack::{intro, outro, progress_bar};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-#[derive(Debug, Clone, Copy, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
 pub enum Features {
     Auth,
 }
@@ -23,9 +22,13 @@ pub enum Features {
 ///   for the current feature. This helps in determining which features are required
 ///   before installing the current feature.
 ///
-/// - `install`: Installs the current feature by iterating over its associated molecules
-///   and routes, invoking their respective installation methods. Returns a `Result`
-///   indicating success or failure of the installation process.
+/// - `steps`: The feature's own molecules and routes.
+///
+/// - `plan`: Resolves the feature, its feature dependencies and every atom they use into
+///   an `InstallPlan` - each step once, dependencies first, cycles reported as errors.
+///
+/// - `install`: Writes every step of the plan, then installs the plan's npm dependencies
+///   in one go. Returns a `Result` indicating success or failure of the installation process.
 ///
 /// # Example
 ///
@@ -50,42 +53,34 @@ impl Features {
             Features::Auth => "The foundational module - login/signup/etc.",
         }
     }
-    pub fn install(&self) -> Result<()> {
+    /// The feature's own molecules and routes, before dependency resolution.
+    pub fn steps(&self) -> Vec<InstallStep> {
         match self {
-            Features::Auth => {
-                intro("Installing Auth")?;
-                let multi = multi_progress("Cloning...");
-                let all_molecules = AuthMolecules::iter();
-                let all_routes = AuthRoutes::iter();
-                let pb_m = multi.add(progress_bar(all_molecules.len() as u64));
-                let pb_r = multi.add(progress_bar(all_routes.len() as u64));
-                pb_m.start("Adding components...");
-                pb_r.start("Adding routes...");
-                let molecule_thread = thread::spawn(move || -> Result<()> {
-                    for molecule in all_molecules {
-                        pb_m.set_message(format!("Writing: {}", molecule.name()));
-                        molecule.install()?;
-                        pb_m.inc(1);
-                    }
-                    pb_m.stop("");
-                    pb_m.clear();
-                    Ok(())
-                });
-                let route_thread = thread::spawn(move || -> Result<()> {
-                    for route in all_routes {
-                        pb_r.set_message(format!("Writing: {}", route.name()));
-                        route.install()?;
-                        pb_r.inc(1);
-                    }
-                    pb_r.stop("");
-                    pb_r.clear();
-                    Ok(())
-                });
-                molecule_thread.join().unwrap()?;
-                route_thread.join().unwrap()?;
-                outro("Auth Installed!")?;
-                Ok(())
-            }
+            Features::Auth => AuthMolecules::iter()
+                .map(InstallStep::Molecule)
+                .chain(AuthRoutes::iter().map(InstallStep::Route))
+                .collect(),
+        }
+    }
+    /// The install plan for the feature, its feature dependencies and every atom they use.
+    pub fn plan(&self) -> Result<InstallPlan> {
+        InstallPlan::new(&[InstallStep::Feature(*self)])
+    }
+    pub fn install(&self) -> Result<()> {
+        intro(format!("Installing {}", self.name()))?;
+        let plan = self.plan()?;
+        plan.choose_options()?;
+        let pb = progress_bar(plan.len() as u64);
+        pb.start("Adding components and routes...");
+        for step in &plan.steps {
+            pb.set_message(format!("Writing: {}", step.name()));
+            step.write()?;
+            pb.inc(1);
         }
+        pb.stop("");
+        pb.clear();
+        writes::npm::check_if_deps_installed(&plan.npm_dependencies())?;
+        outro(format!("{} Installed!", self.name()))?;
+        Ok(())
     }
 }
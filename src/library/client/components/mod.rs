@@ -1,2 +1,8 @@
 pub mod atoms;
+#[cfg(test)]
+mod checks;
 pub mod features;
+pub mod options;
+pub mod plan;
+pub mod remote;
+pub mod sync;
@@ -0,0 +1,147 @@
+use crate::library::client::utils::utils::{ComponentOption, UiComponent};
+use crate::library::config;
+use anyhow::Result;
+use cliclack::select;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::io::{IsTerminal, stdin};
+
+/// The choices recorded for a component under `options.<name>` in `devano.json`.
+pub fn recorded_choices(config: &Value, component: &str) -> HashMap<String, String> {
+    config
+        .get("options")
+        .and_then(|options| options.get(component))
+        .and_then(Value::as_object)
+        .map(|choices| {
+            choices
+                .iter()
+                .filter_map(|(key, id)| Some((key.clone(), id.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Asks for every option the components declare that `devano.json` doesn't already answer,
+/// and records the answers so later installs and upgrades render the same way. Without a
+/// terminal to ask on, the first choice is recorded.
+pub fn choose_options(components: &[&'static UiComponent]) -> Result<()> {
+    let mut config = config::read_config()?;
+    let mut changed = false;
+    for component in components {
+        let recorded = recorded_choices(&config, component.name);
+        for option in component.options {
+            if recorded
+                .get(option.key)
+                .is_some_and(|id| choice_text(option, id).is_some())
+            {
+                continue;
+            }
+            let id = if stdin().is_terminal() {
+                let choices: Vec<(&str, &str, &str)> = option
+                    .choices
+                    .iter()
+                    .map(|choice| (choice.id, choice.id, choice.description))
+                    .collect();
+                select(format!("{}: {}", component.name, option.prompt))
+                    .items(&choices)
+                    .interact()?
+            } else {
+                option.choices[0].id
+            };
+            record_choice(&mut config, component.name, option.key, id);
+            changed = true;
+        }
+    }
+    if changed {
+        config::write_config(&config)?;
+    }
+    Ok(())
+}
+
+fn record_choice(config: &mut Value, component: &str, key: &str, id: &str) {
+    let Some(config) = config.as_object_mut() else {
+        return;
+    };
+    let options = config
+        .entry("options")
+        .or_insert_with(|| Value::Object(Map::new()));
+    if let Some(options) = options.as_object_mut() {
+        let choices = options
+            .entry(component)
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Some(choices) = choices.as_object_mut() {
+            choices.insert(key.to_string(), Value::String(id.to_string()));
+        }
+    }
+}
+
+fn choice_text(option: &ComponentOption, id: &str) -> Option<&'static str> {
+    option
+        .choices
+        .iter()
+        .find(|choice| choice.id == id)
+        .map(|choice| choice.text)
+}
+
+/// Replaces each `{{devano:<key>}}` with the chosen text - the first choice when nothing
+/// valid was chosen.
+pub fn render(component: &UiComponent, choices: &HashMap<String, String>) -> String {
+    let mut contents = component.contents.to_string();
+    for option in component.options {
+        let text = choices
+            .get(option.key)
+            .and_then(|id| choice_text(option, id))
+            .unwrap_or(option.choices[0].text);
+        contents = contents.replace(&format!("{{{{devano:{}}}}}", option.key), text);
+    }
+    contents
+}
+
+/// The component as it should be written into this project, using the choices recorded in
+/// `devano.json`.
+pub fn rendered_contents(component: &UiComponent) -> Result<String> {
+    if component.options.is_empty() {
+        return Ok(component.contents.to_string());
+    }
+    let config = config::read_config()?;
+    Ok(render(
+        component,
+        &recorded_choices(&config, component.name),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::client::templates::{ALL_TEMPLATES, BUTTON};
+
+    #[test]
+    fn renders_recorded_choices_and_falls_back_to_the_first() {
+        let config: Value = serde_json::from_str(
+            r#"{ "options": { "button": { "size": "lg", "default_color": "nope" } } }"#,
+        )
+        .unwrap();
+        let rendered = render(&BUTTON, &recorded_choices(&config, "button"));
+        assert!(rendered.contains("select-none px-6 py-2 text-lg font-semibold"));
+        assert!(rendered.contains(r#"l.color ?? "default";"#));
+    }
+
+    #[test]
+    fn every_choice_leaves_no_placeholders_behind() {
+        for template in ALL_TEMPLATES {
+            for option in template.options {
+                for choice in option.choices {
+                    let choices = HashMap::from([(option.key.to_string(), choice.id.to_string())]);
+                    let rendered = render(template, &choices);
+                    assert!(
+                        !rendered.contains("{{devano:"),
+                        "{} with {}={} still has placeholders",
+                        template.name,
+                        option.key,
+                        choice.id
+                    );
+                }
+            }
+        }
+    }
+}
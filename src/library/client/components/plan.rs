@@ -0,0 +1,240 @@
+use super::atoms::registry::Atoms;
+use super::features::auth::api::AuthRoutes;
+use super::features::auth::ui::AuthMolecules;
+use super::features::registry::Features;
+use super::features::utils::Installable;
+use super::options;
+use super::remote::RemoteComponent;
+use crate::library::client::utils::utils::UiComponent;
+use crate::library::client::writes;
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// A single node in the install graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstallStep {
+    Atom(Atoms),
+    Molecule(AuthMolecules),
+    Route(AuthRoutes),
+    /// Groups a feature's molecules and routes - writes nothing itself.
+    Feature(Features),
+    /// A component from a registry listed in `devano.json`.
+    Remote(&'static RemoteComponent),
+}
+
+impl InstallStep {
+    pub fn name(&self) -> &str {
+        match self {
+            InstallStep::Atom(atom) => atom.get_ui().name,
+            InstallStep::Molecule(molecule) => molecule.name(),
+            InstallStep::Route(route) => route.name(),
+            InstallStep::Feature(feature) => feature.name(),
+            InstallStep::Remote(component) => &component.name,
+        }
+    }
+
+    pub fn dependencies(&self) -> Vec<InstallStep> {
+        match self {
+            InstallStep::Atom(atom) => atom
+                .devano_dependencies()
+                .iter()
+                .map(|dependency| InstallStep::Atom(*dependency))
+                .collect(),
+            InstallStep::Molecule(molecule) => molecule
+                .atom_dependencies()
+                .iter()
+                .map(|dependency| InstallStep::Atom(*dependency))
+                .chain(
+                    molecule
+                        .molecule_dependencies()
+                        .iter()
+                        .map(|dependency| InstallStep::Molecule(*dependency)),
+                )
+                .collect(),
+            InstallStep::Route(_) => vec![],
+            InstallStep::Feature(feature) => feature
+                .feature_dependencies()
+                .iter()
+                .map(|dependency| InstallStep::Feature(*dependency))
+                .chain(feature.steps())
+                .collect(),
+            InstallStep::Remote(component) => component.dependencies(),
+        }
+    }
+
+    /// Writes this step's own file - dependencies are the plan's job.
+    pub fn write(&self) -> Result<()> {
+        match self {
+            InstallStep::Atom(atom) => atom.write(),
+            InstallStep::Molecule(molecule) => molecule.install(),
+            InstallStep::Route(route) => route.install(),
+            InstallStep::Feature(_) => Ok(()),
+            InstallStep::Remote(component) => component.write(),
+        }
+    }
+
+    /// The template this step renders, when it comes from `templates/`.
+    pub fn template(&self) -> Option<&'static UiComponent> {
+        match self {
+            InstallStep::Atom(atom) => Some(atom.get_ui()),
+            InstallStep::Molecule(molecule) => Some(molecule.get_ui()),
+            InstallStep::Route(_) | InstallStep::Feature(_) | InstallStep::Remote(_) => None,
+        }
+    }
+
+    pub fn npm_deps(&self) -> Vec<&'static str> {
+        match self {
+            InstallStep::Atom(atom) => atom.get_ui().npm_deps.to_vec(),
+            InstallStep::Molecule(molecule) => molecule.get_ui().npm_deps.to_vec(),
+            InstallStep::Route(_) | InstallStep::Feature(_) => vec![],
+            InstallStep::Remote(component) => {
+                component.npm_deps.iter().map(String::as_str).collect()
+            }
+        }
+    }
+}
+
+/// Every step needed to install a set of roots, each exactly once, dependencies first.
+#[derive(Debug)]
+pub struct InstallPlan {
+    pub steps: Vec<InstallStep>,
+}
+
+impl InstallPlan {
+    pub fn new(roots: &[InstallStep]) -> Result<Self> {
+        let steps = topological_order(roots, InstallStep::dependencies, |step| {
+            step.name().to_string()
+        })?
+        .into_iter()
+        .filter(|step| !matches!(step, InstallStep::Feature(_)))
+        .collect();
+        Ok(InstallPlan { steps })
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Settles the install-time options of every template in the plan - see [`options`].
+    pub fn choose_options(&self) -> Result<()> {
+        let templates: Vec<&'static UiComponent> = self
+            .steps
+            .iter()
+            .filter_map(InstallStep::template)
+            .collect();
+        options::choose_options(&templates)
+    }
+
+    /// Writes every step in order, then installs the plan's npm dependencies in one go.
+    pub fn install(&self) -> Result<()> {
+        self.choose_options()?;
+        for step in &self.steps {
+            step.write()?;
+        }
+        writes::npm::check_if_deps_installed(&self.npm_dependencies())
+    }
+
+    /// The `npm_deps` of every step, de-duplicated, for a single package-manager call.
+    pub fn npm_dependencies(&self) -> Vec<&'static str> {
+        let mut deps: Vec<&'static str> = Vec::new();
+        for step in &self.steps {
+            for dep in step.npm_deps() {
+                if !deps.contains(&dep) {
+                    deps.push(dep);
+                }
+            }
+        }
+        deps
+    }
+}
+
+/// Depth-first post-order over the graph reachable from `roots`. Errors with the offending
+/// path when a node turns out to depend on itself.
+fn topological_order<T, D, N>(roots: &[T], dependencies: D, name: N) -> Result<Vec<T>>
+where
+    T: Copy + Eq + Hash,
+    D: Fn(&T) -> Vec<T>,
+    N: Fn(&T) -> String,
+{
+    let mut order = Vec::new();
+    let mut done = HashSet::new();
+    let mut path = Vec::new();
+    for root in roots {
+        visit(
+            *root,
+            &dependencies,
+            &name,
+            &mut order,
+            &mut done,
+            &mut path,
+        )?;
+    }
+    Ok(order)
+}
+
+fn visit<T, D, N>(
+    node: T,
+    dependencies: &D,
+    name: &N,
+    order: &mut Vec<T>,
+    done: &mut HashSet<T>,
+    path: &mut Vec<T>,
+) -> Result<()>
+where
+    T: Copy + Eq + Hash,
+    D: Fn(&T) -> Vec<T>,
+    N: Fn(&T) -> String,
+{
+    if done.contains(&node) {
+        return Ok(());
+    }
+    if let Some(start) = path.iter().position(|visiting| *visiting == node) {
+        let cycle: Vec<String> = path[start..]
+            .iter()
+            .chain(std::iter::once(&node))
+            .map(name)
+            .collect();
+        return Err(anyhow!("Dependency cycle: {}", cycle.join(" -> ")));
+    }
+    path.push(node);
+    for dependency in dependencies(&node) {
+        visit(dependency, dependencies, name, order, done, path)?;
+    }
+    path.pop();
+    done.insert(node);
+    order.push(node);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::client::components::atoms::{buttons::Buttons, utils::Utils};
+
+    #[test]
+    fn installs_shared_dependencies_once_and_first() {
+        let roots: Vec<InstallStep> = Atoms::all_variants()
+            .iter()
+            .map(|atom| InstallStep::Atom(*atom))
+            .collect();
+        let plan = InstallPlan::new(&roots).unwrap();
+        let cn = InstallStep::Atom(Atoms::Utils(Utils::Cn));
+        assert_eq!(plan.len(), Atoms::all_variants().len());
+        assert_eq!(plan.steps.iter().filter(|step| **step == cn).count(), 1);
+        let position = |step| plan.steps.iter().position(|s| *s == step).unwrap();
+        assert!(position(cn) < position(InstallStep::Atom(Atoms::Buttons(Buttons::Button))));
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let edges = |node: &u8| match node {
+            1 => vec![2],
+            2 => vec![3],
+            3 => vec![1],
+            _ => vec![],
+        };
+        let error = topological_order(&[1], edges, |node| node.to_string()).unwrap_err();
+        assert_eq!(error.to_string(), "Dependency cycle: 1 -> 2 -> 3 -> 1");
+    }
+}
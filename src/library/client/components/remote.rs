@@ -0,0 +1,412 @@
+use super::atoms::registry::Atoms;
+use super::plan::InstallStep;
+use crate::library::client::templates::ALL_TEMPLATES;
+use crate::library::client::utils::utils::write_file;
+use crate::library::config;
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Component, Path};
+use std::process::Command;
+use std::sync::OnceLock;
+
+/// A component published in a third-party registry index.
+///
+/// ```json
+/// {
+///   "name": "acme",
+///   "components": [
+///     {
+///       "name": "fancy-button",
+///       "description": "Our button, but fancier",
+///       "files": [
+///         { "path": "atoms/buttons/FancyButton.tsx", "source": "FancyButton.tsx" }
+///       ],
+///       "npm_deps": ["clsx@^2"],
+///       "devano_deps": ["cn", "acme/brand-colors"]
+///     }
+///   ]
+/// }
+/// ```
+///
+/// `path` is where the file is installed, relative to `client/src/devano`, and may not
+/// replace a built-in template. Each file carries either its `contents` inline or a `source`
+/// fetched relative to the index - an index served over http(s) may only point below its own
+/// URL. `devano_deps` name built-in atoms, components in the same registry, or
+/// `registry/name` elsewhere.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct RemoteComponent {
+    pub registry: String,
+    pub name: String,
+    pub description: String,
+    pub files: Vec<RemoteFile>,
+    pub npm_deps: Vec<String>,
+    pub devano_deps: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct RemoteFile {
+    pub path: String,
+    pub contents: String,
+}
+
+impl RemoteComponent {
+    /// `registry/name`, unique across every configured registry.
+    pub fn qualified_name(&self) -> String {
+        format!("{}/{}", self.registry, self.name)
+    }
+
+    /// The install steps behind `devano_deps`. Every name was checked when the registries
+    /// were loaded, so anything unresolvable here is simply skipped.
+    pub fn dependencies(&self) -> Vec<InstallStep> {
+        self.devano_deps
+            .iter()
+            .filter_map(|dependency| resolve_dependency(&self.registry, dependency))
+            .collect()
+    }
+
+    pub fn write(&self) -> Result<()> {
+        let base_path = Path::new("client/src/devano");
+        for file in &self.files {
+            write_file(&base_path.join(&file.path), &file.contents)?;
+        }
+        Ok(())
+    }
+}
+
+static REMOTE_COMPONENTS: OnceLock<Vec<RemoteComponent>> = OnceLock::new();
+
+/// Every component from the registries listed in `devano.json`, fetched once per run.
+pub fn remote_components() -> Result<&'static [RemoteComponent]> {
+    if let Some(components) = REMOTE_COMPONENTS.get() {
+        return Ok(components);
+    }
+    let mut components = Vec::new();
+    for location in config::registry_locations(&config::read_config()?) {
+        let index = fetch(&location)?;
+        components.extend(
+            parse_index(&index, &location)
+                .map_err(|e| anyhow!("Invalid registry {}: {}", location, e))?,
+        );
+    }
+    check_dependencies(&components)?;
+    Ok(REMOTE_COMPONENTS.get_or_init(|| components))
+}
+
+/// Finds a remote component by `registry/name`, or by bare name when only one registry has it.
+pub fn find(name: &str) -> Result<Option<&'static RemoteComponent>> {
+    let components = remote_components()?;
+    if name.contains('/') {
+        return Ok(components
+            .iter()
+            .find(|component| component.qualified_name() == name));
+    }
+    let matches: Vec<&RemoteComponent> = components
+        .iter()
+        .filter(|component| component.name == name)
+        .collect();
+    match matches.as_slice() {
+        [] => Ok(None),
+        [component] => Ok(Some(component)),
+        _ => Err(anyhow!(
+            "{} is in more than one registry - use one of: {}",
+            name,
+            matches
+                .iter()
+                .map(|component| component.qualified_name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+fn resolve_dependency(registry: &str, dependency: &str) -> Option<InstallStep> {
+    let components = REMOTE_COMPONENTS
+        .get()
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    remote_dependency(components, registry, dependency)
+        .map(InstallStep::Remote)
+        .or_else(|| Atoms::from_name(dependency).map(InstallStep::Atom))
+}
+
+/// The registry component a `devano_deps` entry names - bare names look in the same registry.
+fn remote_dependency<'a>(
+    components: &'a [RemoteComponent],
+    registry: &str,
+    dependency: &str,
+) -> Option<&'a RemoteComponent> {
+    let qualified = if dependency.contains('/') {
+        dependency.to_string()
+    } else {
+        format!("{}/{}", registry, dependency)
+    };
+    components
+        .iter()
+        .find(|component| component.qualified_name() == qualified)
+}
+
+fn check_dependencies(components: &[RemoteComponent]) -> Result<()> {
+    for component in components {
+        for dependency in &component.devano_deps {
+            let known = remote_dependency(components, &component.registry, dependency).is_some()
+                || Atoms::from_name(dependency).is_some();
+            if !known {
+                return Err(anyhow!(
+                    "{} depends on {}, which isn't a Devano atom or a registry component",
+                    component.qualified_name(),
+                    dependency
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_http(location: &str) -> bool {
+    location.starts_with("http://") || location.starts_with("https://")
+}
+
+/// Reads a local path, a `file://` URL or an `http(s)://` URL - the last through `curl`, which
+/// has to be on the `PATH`.
+pub fn fetch(location: &str) -> Result<String> {
+    if is_http(location) {
+        let output = Command::new("curl")
+            .args(["-fsSL", location])
+            .output()
+            .map_err(|e| match e.kind() {
+                ErrorKind::NotFound => anyhow!(
+                    "Fetching {} needs `curl` - install it, or point devano.json at a local copy of the registry",
+                    location
+                ),
+                _ => anyhow!("Could not run curl to fetch {}: {}", location, e),
+            })?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Could not fetch {}: {}",
+                location,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        return Ok(String::from_utf8(output.stdout)?);
+    }
+    let path = location.strip_prefix("file://").unwrap_or(location);
+    fs::read_to_string(path).map_err(|e| anyhow!("Could not read {}: {}", path, e))
+}
+
+/// `source` relative to the index it was listed in. A local index may also name an absolute
+/// path or URL, but one fetched over http(s) can only reach files below its own URL - it
+/// shouldn't be able to read the machine it's installed on.
+fn sibling(index_location: &str, source: &str) -> Result<String> {
+    let absolute = source.contains("://") || source.starts_with('/');
+    if is_http(index_location) && (absolute || source.contains('\\') || !stays_inside(source)) {
+        return Err(anyhow!(
+            "{} can only use sources below its own URL, not {}",
+            index_location,
+            source
+        ));
+    }
+    if absolute {
+        return Ok(source.to_string());
+    }
+    Ok(match index_location.rfind('/') {
+        Some(slash) => format!("{}{}", &index_location[..=slash], source),
+        None => source.to_string(),
+    })
+}
+
+/// A relative path that never climbs out of the folder it's joined onto.
+fn stays_inside(path: &str) -> bool {
+    Path::new(path)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+/// Whether `path` is where a built-in template installs.
+fn is_built_in(path: &str) -> bool {
+    let path: Vec<Component> = Path::new(path)
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect();
+    ALL_TEMPLATES.iter().any(|template| {
+        Path::new(template.folder_path)
+            .join(template.filename)
+            .components()
+            .eq(path.iter().copied())
+    })
+}
+
+pub fn parse_index(index: &str, location: &str) -> Result<Vec<RemoteComponent>> {
+    let index: Value = serde_json::from_str(index)?;
+    let registry = index
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("the index needs a `name`"))?;
+    let strings = |component: &Value, key: &str| -> Vec<String> {
+        component
+            .get(key)
+            .and_then(Value::as_array)
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let mut components = Vec::new();
+    for component in index
+        .get("components")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("the index needs a `components` array"))?
+    {
+        let name = component
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("every component needs a `name`"))?;
+        let mut files = Vec::new();
+        for file in component
+            .get("files")
+            .and_then(Value::as_array)
+            .ok_or_else(|| anyhow!("{} needs a `files` array", name))?
+        {
+            let path = file
+                .get("path")
+                .and_then(Value::as_str)
+                .ok_or_else(|| anyhow!("every file in {} needs a `path`", name))?;
+            if !stays_inside(path) || path.contains('\\') {
+                return Err(anyhow!(
+                    "{} would install outside src/devano: {}",
+                    name,
+                    path
+                ));
+            }
+            if is_built_in(path) {
+                return Err(anyhow!(
+                    "{} would overwrite the built-in {} - install it under its own folder",
+                    name,
+                    path
+                ));
+            }
+            let contents = match (
+                file.get("contents").and_then(Value::as_str),
+                file.get("source").and_then(Value::as_str),
+            ) {
+                (Some(contents), _) => contents.to_string(),
+                (None, Some(source)) => fetch(&sibling(location, source)?)?,
+                (None, None) => {
+                    return Err(anyhow!(
+                        "{} in {} needs `contents` or a `source`",
+                        path,
+                        name
+                    ));
+                }
+            };
+            files.push(RemoteFile {
+                path: path.to_string(),
+                contents,
+            });
+        }
+        components.push(RemoteComponent {
+            registry: registry.to_string(),
+            name: name.to_string(),
+            description: component
+                .get("description")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            files,
+            npm_deps: strings(component, "npm_deps"),
+            devano_deps: strings(component, "devano_deps"),
+        });
+    }
+    Ok(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn resolves_sources_next_to_the_index() {
+        assert_eq!(
+            sibling("https://acme.dev/devano/index.json", "Fancy.tsx").unwrap(),
+            "https://acme.dev/devano/Fancy.tsx"
+        );
+        assert_eq!(
+            sibling("file:///srv/registry/index.json", "atoms/Fancy.tsx").unwrap(),
+            "file:///srv/registry/atoms/Fancy.tsx"
+        );
+        assert_eq!(sibling("index.json", "Fancy.tsx").unwrap(), "Fancy.tsx");
+        assert_eq!(
+            sibling("./registry/index.json", "/srv/shared/Fancy.tsx").unwrap(),
+            "/srv/shared/Fancy.tsx"
+        );
+    }
+
+    #[test]
+    fn keeps_http_sources_below_the_index() {
+        let index = "https://acme.dev/devano/index.json";
+        for source in [
+            "/etc/passwd",
+            "file:///home/me/.ssh/id_ed25519",
+            "ftp://acme.dev/Fancy.tsx",
+            "https://elsewhere.dev/Fancy.tsx",
+            "//elsewhere.dev/Fancy.tsx",
+            "../secrets/Fancy.tsx",
+            "atoms/../../Fancy.tsx",
+        ] {
+            assert!(sibling(index, source).is_err(), "{}", source);
+        }
+        assert_eq!(
+            sibling(index, "./atoms/Fancy.tsx").unwrap(),
+            "https://acme.dev/devano/./atoms/Fancy.tsx"
+        );
+    }
+
+    #[test]
+    fn parses_inline_and_fetched_files() {
+        let dir = env::temp_dir().join("devano-remote-registry-test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("Fancy.tsx"), "export function Fancy() {}\n").unwrap();
+        let location = format!("file://{}/index.json", dir.display());
+        let index = r#"{
+            "name": "acme",
+            "components": [{
+                "name": "fancy",
+                "files": [
+                    { "path": "atoms/acme/Fancy.tsx", "source": "Fancy.tsx" },
+                    { "path": "atoms/acme/index.ts", "contents": "export * from \"./Fancy\";\n" }
+                ],
+                "npm_deps": ["clsx@^2"],
+                "devano_deps": ["cn"]
+            }]
+        }"#;
+        let components = parse_index(index, &location).unwrap();
+        assert_eq!(components[0].qualified_name(), "acme/fancy");
+        assert_eq!(
+            components[0].files[0].contents,
+            "export function Fancy() {}\n"
+        );
+        assert_eq!(components[0].files[1].path, "atoms/acme/index.ts");
+        assert!(check_dependencies(&components).is_ok());
+
+        for path in [
+            "../../package.json",
+            "atoms/../../package.json",
+            "/etc/hosts",
+        ] {
+            let escaping = index.replace("atoms/acme/index.ts", path);
+            assert!(parse_index(&escaping, &location).is_err(), "{}", path);
+        }
+        for path in ["utils/cn.ts", "./atoms/buttons/Button.tsx"] {
+            let overwriting = index.replace("atoms/acme/index.ts", path);
+            let error = parse_index(&overwriting, &location).unwrap_err();
+            assert!(error.to_string().contains("built-in"), "{}", error);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use super::options::render;
+use crate::library::client::templates::ALL_TEMPLATES;
+use crate::library::client::utils::utils::UiComponent;
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// What `devano sync` found for one file.
+#[derive(Debug, PartialEq)]
+pub enum SyncChange {
+    Unchanged(PathBuf),
+    Updated {
+        path: PathBuf,
+        added: usize,
+        removed: usize,
+    },
+    /// In the workshop, but no manifest entry installs it.
+    Unregistered(PathBuf),
+    /// Registered, but the workshop doesn't have it.
+    Missing(PathBuf),
+    /// Changed, but the template has install-time options and the workshop copy has them
+    /// rendered - copying it back would bake one choice in.
+    Rendered(PathBuf),
+}
+
+/// The `templates/` of the devano checkout you're running from - where `build.rs` reads
+/// templates from. An installed binary can't know where it was built, so this looks up
+/// from the working directory instead.
+pub fn find_templates_path() -> Result<PathBuf> {
+    let cwd = env::current_dir()?;
+    templates_path_from(&cwd).ok_or_else(|| {
+        anyhow!(
+            "No templates/manifest.json in {:?} or above it - run this from a devano checkout or pass --templates <path>",
+            cwd
+        )
+    })
+}
+
+fn templates_path_from(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|candidate| candidate.join("templates"))
+        .find(|templates| templates.join("manifest.json").is_file())
+}
+
+/// Compares a workshop project's `src/devano` against the registered templates and, unless
+/// `dry_run`, copies every changed file over its template.
+pub fn sync_workshop(workshop: &Path, templates: &Path, dry_run: bool) -> Result<Vec<SyncChange>> {
+    let devano = workshop.join("src").join("devano");
+    if !devano.is_dir() {
+        return Err(anyhow!("{:?} has no src/devano folder", workshop));
+    }
+    let registered: HashMap<PathBuf, &UiComponent> = ALL_TEMPLATES
+        .iter()
+        .map(|template| {
+            (
+                Path::new(template.folder_path).join(template.filename),
+                *template,
+            )
+        })
+        .collect();
+
+    let mut changes = Vec::new();
+    for entry in WalkDir::new(&devano).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let path = entry.path().strip_prefix(&devano)?.to_path_buf();
+        if path.starts_with("api") {
+            continue; // scaffolded by `devano new` and feature routes, not templates
+        }
+        let Some(template) = registered.get(&path) else {
+            changes.push(SyncChange::Unregistered(path));
+            continue;
+        };
+        let workshop_contents = fs::read_to_string(entry.path())?;
+        if workshop_contents == render(template, &HashMap::new()) {
+            changes.push(SyncChange::Unchanged(path));
+            continue;
+        }
+        let keeps_placeholders = template
+            .options
+            .iter()
+            .all(|option| workshop_contents.contains(&format!("{{{{devano:{}}}}}", option.key)));
+        if !keeps_placeholders {
+            changes.push(SyncChange::Rendered(path));
+            continue;
+        }
+        // a registered template that can't be read means `templates` is the wrong folder
+        let template_path = templates.join(&path);
+        let template_contents = fs::read_to_string(&template_path).map_err(|e| {
+            anyhow!(
+                "Could not read the {} template at {:?}: {}",
+                template.name,
+                template_path,
+                e
+            )
+        })?;
+        if workshop_contents == template_contents {
+            changes.push(SyncChange::Unchanged(path));
+            continue;
+        }
+        let (added, removed) = line_changes(&template_contents, &workshop_contents);
+        if !dry_run {
+            if let Some(parent) = template_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&template_path, &workshop_contents)?;
+        }
+        changes.push(SyncChange::Updated {
+            path,
+            added,
+            removed,
+        });
+    }
+    let mut registered: Vec<PathBuf> = registered.into_keys().collect();
+    registered.sort();
+    for path in registered {
+        if !devano.join(&path).exists() {
+            changes.push(SyncChange::Missing(path));
+        }
+    }
+    Ok(changes)
+}
+
+/// `(added, removed)` line counts between two versions of a file, via their longest common
+/// subsequence of lines.
+pub fn line_changes(old: &str, new: &str) -> (usize, usize) {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for (i, old_line) in old.iter().enumerate().rev() {
+        for (j, new_line) in new.iter().enumerate().rev() {
+            lengths[i][j] = if old_line == new_line {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let common = lengths[0][0];
+    (new.len() - common, old.len() - common)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_added_and_removed_lines() {
+        assert_eq!(line_changes("a\nb\nc\n", "a\nb\nc\n"), (0, 0));
+        assert_eq!(line_changes("a\nb\nc\n", "a\nx\nc\nd\n"), (2, 1));
+        assert_eq!(line_changes("", "a\n"), (1, 0));
+    }
+
+    #[test]
+    fn copies_changed_registered_files_into_templates() {
+        let root = env::temp_dir().join("devano-sync-test");
+        let _ = fs::remove_dir_all(&root);
+        let workshop = root.join("workshop");
+        let templates = root.join("templates");
+        let devano = workshop.join("src").join("devano");
+        fs::create_dir_all(devano.join("utils")).unwrap();
+        fs::create_dir_all(devano.join("atoms").join("scratch")).unwrap();
+        fs::write(
+            devano.join("utils").join("cn.ts"),
+            "export const cn = () => \"\";\n",
+        )
+        .unwrap();
+        fs::write(devano.join("atoms").join("scratch").join("Wip.tsx"), "").unwrap();
+        fs::create_dir_all(templates.join("utils")).unwrap();
+        fs::write(
+            templates.join("utils").join("cn.ts"),
+            "export const cn = () => \"x\";\n",
+        )
+        .unwrap();
+        fs::create_dir_all(devano.join("atoms").join("layout")).unwrap();
+        let card = ALL_TEMPLATES
+            .iter()
+            .find(|template| template.name == "card")
+            .unwrap();
+        fs::write(
+            devano.join("atoms").join("layout").join("Card.tsx"),
+            render(
+                card,
+                &HashMap::from([("radius".to_string(), "xl".to_string())]),
+            ),
+        )
+        .unwrap();
+
+        let changes = sync_workshop(&workshop, &templates, false).unwrap();
+        assert!(changes.contains(&SyncChange::Unregistered(PathBuf::from(
+            "atoms/scratch/Wip.tsx"
+        ))));
+        assert!(changes.contains(&SyncChange::Updated {
+            path: PathBuf::from("utils/cn.ts"),
+            added: 1,
+            removed: 1,
+        }));
+        assert!(changes.contains(&SyncChange::Rendered(PathBuf::from(
+            "atoms/layout/Card.tsx"
+        ))));
+        assert!(
+            !templates
+                .join("atoms")
+                .join("layout")
+                .join("Card.tsx")
+                .exists()
+        );
+        assert!(changes.contains(&SyncChange::Missing(PathBuf::from(
+            "atoms/buttons/Button.tsx"
+        ))));
+        assert_eq!(
+            fs::read_to_string(templates.join("utils").join("cn.ts")).unwrap(),
+            "export const cn = () => \"\";\n"
+        );
+    }
+
+    #[test]
+    fn finds_the_templates_folder_above_the_working_directory() {
+        let root = env::temp_dir().join("devano-find-templates-test");
+        let _ = fs::remove_dir_all(&root);
+        let nested = root.join("src").join("library");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(templates_path_from(&nested), None);
+
+        fs::create_dir_all(root.join("templates")).unwrap();
+        fs::write(root.join("templates").join("manifest.json"), "{}").unwrap();
+        assert_eq!(templates_path_from(&nested), Some(root.join("templates")));
+    }
+
+    #[test]
+    fn fails_when_a_registered_template_is_missing() {
+        let root = env::temp_dir().join("devano-sync-missing-test");
+        let _ = fs::remove_dir_all(&root);
+        let devano = root.join("workshop").join("src").join("devano");
+        fs::create_dir_all(devano.join("utils")).unwrap();
+        fs::write(devano.join("utils").join("cn.ts"), "changed\n").unwrap();
+
+        let error =
+            sync_workshop(&root.join("workshop"), &root.join("templates"), true).unwrap_err();
+        assert!(
+            error.to_string().contains("Could not read the cn template"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod transforms;
+pub mod writes;
@@ -0,0 +1,258 @@
+use anyhow::{Result, anyhow};
+use regex::Regex;
+
+/// An SVG file cleaned up into what an icon component needs.
+#[derive(Debug, PartialEq)]
+pub struct SvgIcon {
+    /// The component name, e.g. `ChevronDown` - also the file name.
+    pub component: String,
+    /// The file stem it came from, e.g. `chevron-down`.
+    pub slug: String,
+    pub view_box: String,
+    /// Presentation attributes from the root `<svg>` that the children inherit.
+    pub attributes: Vec<(String, String)>,
+    /// The children of the root `<svg>`, one element per line.
+    pub body: String,
+}
+
+/// The first line of every generated icon - what tells them apart from hand-written templates.
+pub const GENERATED_HEADER: &str = "// Generated by `devano icons`";
+
+/// Root attributes worth keeping - everything else on `<svg>` is either recomputed by the
+/// shared `Icon` (size, xmlns) or editor noise.
+const INHERITED_ATTRIBUTES: &[&str] = &[
+    "fill",
+    "stroke",
+    "stroke-width",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "fill-rule",
+    "clip-rule",
+];
+
+/// `chevron-down`, `chevron_down` or `chevron down` -> `ChevronDown`. Names that would
+/// start with a digit get an `Icon` prefix.
+pub fn component_name(slug: &str) -> String {
+    let name: String = slug
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Icon{}", name)
+    } else {
+        name
+    }
+}
+
+/// A color that should follow the text color instead.
+fn is_fixed_color(value: &str) -> bool {
+    let value = value.trim();
+    !(value.is_empty()
+        || value == "none"
+        || value.eq_ignore_ascii_case("currentColor")
+        || value.starts_with("url("))
+}
+
+/// Strips editor metadata, pulls the `viewBox` (from `width`/`height` when there isn't
+/// one) and turns every fixed fill and stroke into `currentColor`.
+pub fn normalize_svg(slug: &str, source: &str) -> Result<SvgIcon> {
+    let noise = Regex::new(
+        r"(?s)<\?xml.*?\?>|<!DOCTYPE[^>]*>|<!--.*?-->|<(metadata|title|desc|sodipodi:namedview)\b[^>]*/>|<(metadata|title|desc|sodipodi:namedview)\b[^>]*>.*?</(metadata|title|desc|sodipodi:namedview)>",
+    )
+    .expect("Failed to compile regex");
+    let source = noise.replace_all(source, "");
+
+    let root = Regex::new(r"(?s)<svg\b([^>]*)>(.*)</svg>").expect("Failed to compile regex");
+    let caps = root
+        .captures(&source)
+        .ok_or_else(|| anyhow!("{}.svg has no <svg> element", slug))?;
+    let attribute =
+        Regex::new(r#"([\w:-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("Failed to compile regex");
+    let root_attributes: Vec<(String, String)> = attribute
+        .captures_iter(&caps[1])
+        .map(|attr| {
+            let value = attr.get(2).or(attr.get(3)).map_or("", |m| m.as_str());
+            (attr[1].to_string(), value.to_string())
+        })
+        .collect();
+    let root_attribute = |name: &str| {
+        root_attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    let view_box = match root_attribute("viewBox") {
+        Some(view_box) => view_box.split_whitespace().collect::<Vec<_>>().join(" "),
+        None => {
+            let length = |name: &str| {
+                root_attribute(name)
+                    .map(|value| value.trim_end_matches("px"))
+                    .filter(|value| value.parse::<f64>().is_ok())
+            };
+            match (length("width"), length("height")) {
+                (Some(width), Some(height)) => format!("0 0 {} {}", width, height),
+                _ => {
+                    return Err(anyhow!(
+                        "{}.svg needs a viewBox, or a numeric width and height",
+                        slug
+                    ));
+                }
+            }
+        }
+    };
+
+    let attributes = INHERITED_ATTRIBUTES
+        .iter()
+        .filter_map(|name| {
+            let value = root_attribute(name)?;
+            let value = if (*name == "fill" || *name == "stroke") && is_fixed_color(value) {
+                "currentColor"
+            } else {
+                value
+            };
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect();
+
+    Ok(SvgIcon {
+        component: component_name(slug),
+        slug: slug.to_string(),
+        view_box,
+        attributes,
+        body: normalize_body(&caps[2]),
+    })
+}
+
+fn normalize_body(body: &str) -> String {
+    let editor_attributes =
+        Regex::new(r#"\s(?:xmlns(?::[\w-]+)?|sodipodi:[\w-]+|inkscape:[\w-]+|data-name)\s*=\s*(?:"[^"]*"|'[^']*')"#)
+            .expect("Failed to compile regex");
+    // whitespace first, so `data-fill="..."` and friends are left alone
+    let color_attribute =
+        Regex::new(r#"(\s)(fill|stroke)\s*=\s*"([^"]*)""#).expect("Failed to compile regex");
+    // the whole value, up to the end of the declaration - `url(#a)` has to be seen in one piece
+    let color_style = Regex::new(r#"([;{"'\s])(fill|stroke)\s*:\s*([^;{}"']*)"#)
+        .expect("Failed to compile regex");
+    let empty_element =
+        Regex::new(r"<([\w:-]+)([^<>]*?)\s*>\s*</([\w:-]+)>").expect("Failed to compile regex");
+    let between_tags = Regex::new(r">\s*<").expect("Failed to compile regex");
+
+    let body = editor_attributes.replace_all(body, "");
+    let body = color_attribute.replace_all(&body, |caps: &regex::Captures| {
+        if is_fixed_color(&caps[3]) {
+            format!(r#"{}{}="currentColor""#, &caps[1], &caps[2])
+        } else {
+            caps[0].to_string()
+        }
+    });
+    let body = color_style.replace_all(&body, |caps: &regex::Captures| {
+        if is_fixed_color(&caps[3]) {
+            format!("{}{}:currentColor", &caps[1], &caps[2])
+        } else {
+            caps[0].to_string()
+        }
+    });
+    // `<path d="..."></path>` -> `<path d="..." />`, leaving non-empty groups alone
+    let body = empty_element.replace_all(&body, |caps: &regex::Captures| {
+        if caps[1] == caps[3] {
+            format!("<{}{} />", &caps[1], &caps[2])
+        } else {
+            caps[0].to_string()
+        }
+    });
+    let body = between_tags.replace_all(&body, ">\n<");
+    body.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .map(|line| line.replace("/>", " />").replace("  />", " />"))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The Solid component for an icon - a thin wrapper around the shared `Icon`, so every
+/// icon takes the same `IconProps`.
+pub fn icon_component(icon: &SvgIcon) -> String {
+    let mut lines = vec![
+        format!("{} from {}.svg", GENERATED_HEADER, icon.slug),
+        "import { Icon } from \"~/devano/atoms/icons/Icon\";".to_string(),
+        "import type { IconProps } from \"~/devano/atoms/icons/Icon\";".to_string(),
+        String::new(),
+        format!("export function {}(props: IconProps) {{", icon.component),
+        "\treturn (".to_string(),
+        "\t\t<Icon".to_string(),
+        format!("\t\t\tviewBox=\"{}\"", icon.view_box),
+    ];
+    for (name, value) in &icon.attributes {
+        lines.push(format!("\t\t\t{}=\"{}\"", name, value));
+    }
+    lines.push("\t\t\t{...props}".to_string());
+    lines.push("\t\t>".to_string());
+    for line in icon.body.lines() {
+        lines.push(format!("\t\t\t{}", line));
+    }
+    lines.push("\t\t</Icon>".to_string());
+    lines.push("\t);".to_string());
+    lines.push("}".to_string());
+    lines.push(String::new());
+    lines.push(format!("export default {};", icon.component));
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_components_in_pascal_case() {
+        assert_eq!(component_name("chevron-down"), "ChevronDown");
+        assert_eq!(component_name("alert_triangle"), "AlertTriangle");
+        assert_eq!(component_name("3d-box"), "Icon3dBox");
+    }
+
+    #[test]
+    fn normalizes_editor_exports() {
+        let source = r##"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Generator: Some Editor -->
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:inkscape="http://www.inkscape.org" width="24px" height="24px" fill="#1a1a1a">
+  <title>Star</title>
+  <metadata><rdf:RDF></rdf:RDF></metadata>
+  <g inkscape:label="Layer 1" data-name="Layer 1">
+    <path d="M12 2l3 7h7l-6 4 2 7-6-4-6 4 2-7-6-4h7z" stroke="black" style="fill:#ff0000;opacity:0.5"></path>
+    <circle cx="12" cy="12" r="2" fill="none"/>
+    <rect width="4" height="4" data-fill="#123456" style="fill: url(#shine); stroke:#000" />
+    <rect width="2" height="2" fill="url(#shine)" />
+  </g>
+</svg>"##;
+        let icon = normalize_svg("star", source).unwrap();
+        assert_eq!(icon.component, "Star");
+        assert_eq!(icon.view_box, "0 0 24 24");
+        assert_eq!(
+            icon.attributes,
+            vec![("fill".to_string(), "currentColor".to_string())]
+        );
+        assert_eq!(
+            icon.body,
+            r##"<g>
+<path d="M12 2l3 7h7l-6 4 2 7-6-4-6 4 2-7-6-4h7z" stroke="currentColor" style="fill:currentColor;opacity:0.5" />
+<circle cx="12" cy="12" r="2" fill="none" />
+<rect width="4" height="4" data-fill="#123456" style="fill: url(#shine); stroke:currentColor" />
+<rect width="2" height="2" fill="url(#shine)" />
+</g>"##
+        );
+        assert!(icon_component(&icon).contains("export function Star(props: IconProps)"));
+    }
+
+    #[test]
+    fn requires_a_size() {
+        assert!(normalize_svg("blank", "<svg><path d=\"M0 0\" /></svg>").is_err());
+        assert!(normalize_svg("text", "not an svg").is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use super::transforms::{GENERATED_HEADER, SvgIcon, icon_component, normalize_svg};
+use crate::library::client::components::atoms::icons::Icons;
+use crate::library::client::components::atoms::registry::Atoms;
+use crate::library::client::templates::ALL_TEMPLATES;
+use anyhow::{Result, anyhow};
+use serde_json::{Value, json};
+use std::fs;
+use std::path::Path;
+
+/// Where icons live, relative to `src/devano` - and to `templates/`.
+pub const ICONS_FOLDER: &str = "atoms/icons";
+
+/// Every `.svg` directly inside `dir`, normalized, in file name order. Names a hand-written
+/// template already uses - `icon.svg` would replace the shared `Icon` - are refused.
+pub fn read_icons(dir: &Path) -> Result<Vec<SvgIcon>> {
+    if !dir.is_dir() {
+        return Err(anyhow!("{:?} isn't a directory", dir));
+    }
+    let mut paths: Vec<_> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "svg"))
+        .collect();
+    paths.sort();
+
+    let mut icons: Vec<SvgIcon> = Vec::new();
+    for path in paths {
+        let slug = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| anyhow!("{:?} has no usable file name", path))?;
+        let icon = normalize_svg(slug, &fs::read_to_string(&path)?)?;
+        if let Some(template) = ALL_TEMPLATES.iter().find(|template| {
+            !template.contents.starts_with(GENERATED_HEADER)
+                && Path::new(template.filename).file_stem() == Some(icon.component.as_ref())
+        }) {
+            return Err(anyhow!(
+                "{}.svg would become {}, which is already the built-in {} - rename the file",
+                icon.slug,
+                icon.component,
+                template.name
+            ));
+        }
+        if let Some(clash) = icons.iter().find(|other| other.component == icon.component) {
+            return Err(anyhow!(
+                "{}.svg and {}.svg would both become {}",
+                clash.slug,
+                icon.slug,
+                icon.component
+            ));
+        }
+        icons.push(icon);
+    }
+    Ok(icons)
+}
+
+/// Writes the icons into this project's `client/src/devano`, installing the shared `Icon`
+/// they wrap first. Icons already there are regenerated.
+pub fn write_project_icons(icons: &[SvgIcon]) -> Result<()> {
+    Atoms::Icons(Icons::Icon).install()?;
+    let base_path = Path::new("client/src/devano").join(ICONS_FOLDER);
+    for icon in icons {
+        write_generated(
+            &base_path.join(format!("{}.tsx", icon.component)),
+            &icon_component(icon),
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes the icons into a templates folder and registers any new ones in its
+/// `manifest.json`, after the icons already there. Existing entries keep their name and
+/// description - only the component is regenerated. Returns how many were new.
+pub fn write_template_icons(icons: &[SvgIcon], templates: &Path) -> Result<usize> {
+    let manifest_path = templates.join("manifest.json");
+    let mut manifest: Value = serde_json::from_str(&fs::read_to_string(&manifest_path)?)
+        .map_err(|e| anyhow!("Could not parse {:?}: {}", manifest_path, e))?;
+    let components = manifest
+        .get_mut("components")
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| anyhow!("{:?} needs a `components` array", manifest_path))?;
+
+    let in_folder = |component: &Value| component["folder_path"] == ICONS_FOLDER;
+    let registered = |components: &[Value], filename: &str| {
+        components
+            .iter()
+            .any(|component| in_folder(component) && component["filename"] == filename)
+    };
+    // check every new entry before writing anything, so a clash leaves the folder as it was
+    let mut new_entries = Vec::new();
+    for icon in icons {
+        let filename = format!("{}.tsx", icon.component);
+        if registered(components, &filename) {
+            continue;
+        }
+        let entry = manifest_entry(icon, &filename);
+        if components
+            .iter()
+            .any(|component| component["const"] == entry["const"])
+        {
+            return Err(anyhow!(
+                "{}.svg would be registered as {}, which {:?} already uses",
+                icon.slug,
+                entry["const"],
+                manifest_path
+            ));
+        }
+        new_entries.push(entry);
+    }
+
+    for icon in icons {
+        write_generated(
+            &templates
+                .join(ICONS_FOLDER)
+                .join(format!("{}.tsx", icon.component)),
+            &icon_component(icon),
+        )?;
+    }
+    let added = new_entries.len();
+    for entry in new_entries {
+        let position = components
+            .iter()
+            .rposition(in_folder)
+            .map_or(components.len(), |last| last + 1);
+        components.insert(position, entry);
+    }
+
+    fs::write(
+        &manifest_path,
+        serde_json::to_string_pretty(&manifest)? + "\n",
+    )?;
+    Ok(added)
+}
+
+/// Like `write_file`, but replaces what's there - the components are generated, so a
+/// rerun should pick up edited SVGs.
+fn write_generated(file_path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = file_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(file_path, contents)?;
+    Ok(())
+}
+
+/// `ChevronDown` -> `CHEVRON_DOWN`, `Icon3dBox` -> `ICON3D_BOX`.
+fn upper_snake(component: &str) -> String {
+    let mut snake = String::new();
+    let mut previous: Option<char> = None;
+    for c in component.chars() {
+        if c.is_ascii_uppercase() && previous.is_some_and(|p| !p.is_ascii_uppercase()) {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_uppercase());
+        previous = Some(c);
+    }
+    snake
+}
+
+fn manifest_entry(icon: &SvgIcon, filename: &str) -> Value {
+    let words = icon.slug.replace(['-', '_'], " ");
+    let mut description: String = words.trim().to_string();
+    if let Some(first) = description.get(..1) {
+        description = first.to_uppercase() + &description[1..];
+    }
+    json!({
+        "const": format!("{}_ICON", upper_snake(&icon.component)),
+        "name": format!("{}-icon", icon.slug.replace(['_', ' '], "-")),
+        "folder_path": ICONS_FOLDER,
+        "filename": filename,
+        "description": format!("{} icon", description),
+        "long_description": "",
+        "npm_deps": []
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn registers_new_icons_after_the_existing_ones() {
+        let root = env::temp_dir().join("devano-icon-templates-test");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(
+            root.join("manifest.json"),
+            r#"{ "components": [
+                { "const": "ICON", "name": "icon", "folder_path": "atoms/icons", "filename": "Icon.tsx" },
+                { "const": "CN", "name": "cn", "folder_path": "utils", "filename": "cn.ts" }
+            ] }"#,
+        )
+        .unwrap();
+        let svg = r#"<svg viewBox="0 0 24 24"><path d="M4 6h16" /></svg>"#;
+        let icons = vec![normalize_svg("arrow-up", svg).unwrap()];
+
+        assert_eq!(write_template_icons(&icons, &root).unwrap(), 1);
+        assert_eq!(write_template_icons(&icons, &root).unwrap(), 0);
+
+        let manifest: Value =
+            serde_json::from_str(&fs::read_to_string(root.join("manifest.json")).unwrap()).unwrap();
+        let components = manifest["components"].as_array().unwrap();
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[1]["const"], "ARROW_UP_ICON");
+        assert_eq!(components[1]["name"], "arrow-up-icon");
+        assert_eq!(components[1]["description"], "Arrow up icon");
+        assert!(root.join("atoms/icons/ArrowUp.tsx").exists());
+    }
+
+    #[test]
+    fn builds_consts_from_the_component_name() {
+        assert_eq!(upper_snake("ChevronDown"), "CHEVRON_DOWN");
+        assert_eq!(upper_snake("Icon3dBox"), "ICON3D_BOX");
+        assert_eq!(upper_snake("X"), "X");
+
+        let manifest: Value = serde_json::from_str(
+            &fs::read_to_string(
+                Path::new(env!("CARGO_MANIFEST_DIR")).join("templates/manifest.json"),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let components = manifest["components"].as_array().unwrap();
+        let icons = read_icons(&Path::new(env!("CARGO_MANIFEST_DIR")).join("icons")).unwrap();
+        assert!(!icons.is_empty());
+        for icon in icons {
+            let filename = format!("{}.tsx", icon.component);
+            let existing = components
+                .iter()
+                .find(|component| {
+                    component["folder_path"] == ICONS_FOLDER
+                        && component["filename"] == filename.as_str()
+                })
+                .unwrap_or_else(|| panic!("{} isn't registered", filename));
+            assert_eq!(manifest_entry(&icon, &filename)["const"], existing["const"]);
+        }
+    }
+
+    #[test]
+    fn refuses_names_of_hand_written_templates() {
+        let root = env::temp_dir().join("devano-icon-clash-test");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let svg = r#"<svg viewBox="0 0 24 24"><path d="M4 6h16" /></svg>"#;
+        for clash in ["icon.svg", "button.svg"] {
+            fs::write(root.join(clash), svg).unwrap();
+            let error = read_icons(&root).unwrap_err();
+            assert!(error.to_string().contains("built-in"), "{}", error);
+            fs::remove_file(root.join(clash)).unwrap();
+        }
+        fs::write(root.join("check.svg"), svg).unwrap();
+        assert_eq!(read_icons(&root).unwrap()[0].component, "Check");
+    }
+}
@@ -1,4 +1,9 @@
 pub mod colors;
 pub mod components;
+pub mod icons;
+pub mod motion;
+pub mod templates;
+pub mod tokens;
+pub mod typography;
 pub mod utils;
 pub mod writes;
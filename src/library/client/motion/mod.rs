@@ -0,0 +1,3 @@
+pub mod named_motions;
+pub mod transforms;
+pub mod writes;
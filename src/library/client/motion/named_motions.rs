@@ -0,0 +1,78 @@
+use super::transforms::{Easing, MotionSet};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(EnumIter, Debug)]
+pub enum NamedMotion {
+    Default,
+    Snappy,
+    Calm,
+    Playful,
+}
+
+impl NamedMotion {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NamedMotion::Default => "default",
+            NamedMotion::Snappy => "snappy",
+            NamedMotion::Calm => "calm",
+            NamedMotion::Playful => "playful",
+        }
+    }
+    // Get the corresponding Description for the enum variant
+    pub fn description(&self) -> &'static str {
+        match self {
+            NamedMotion::Default => "120/200/320ms, standard curves. Noticeable, never in the way.",
+            NamedMotion::Snappy => "80/140/220ms, decelerating curves. Tools & dashboards.",
+            NamedMotion::Calm => "180/300/480ms, emphasized curves. Content & marketing.",
+            NamedMotion::Playful => "140/240/400ms, springy entrances. Consumer apps.",
+        }
+    }
+
+    /// Get the corresponding `MotionSet` for the enum variant
+    pub fn get_motion(&self) -> MotionSet {
+        match self {
+            NamedMotion::Default => MotionSet {
+                fast_ms: 120,
+                base_ms: 200,
+                slow_ms: 320,
+                ease_standard: Easing::Standard,
+                ease_enter: Easing::Decelerate,
+                ease_exit: Easing::Accelerate,
+                distance_px: 8,
+            },
+            NamedMotion::Snappy => MotionSet {
+                fast_ms: 80,
+                base_ms: 140,
+                slow_ms: 220,
+                ease_standard: Easing::Decelerate,
+                ease_enter: Easing::Decelerate,
+                ease_exit: Easing::Accelerate,
+                distance_px: 4,
+            },
+            NamedMotion::Calm => MotionSet {
+                fast_ms: 180,
+                base_ms: 300,
+                slow_ms: 480,
+                ease_standard: Easing::Standard,
+                ease_enter: Easing::Emphasized,
+                ease_exit: Easing::Accelerate,
+                distance_px: 12,
+            },
+            NamedMotion::Playful => MotionSet {
+                fast_ms: 140,
+                base_ms: 240,
+                slow_ms: 400,
+                ease_standard: Easing::Standard,
+                ease_enter: Easing::Spring,
+                ease_exit: Easing::Accelerate,
+                distance_px: 16,
+            },
+        }
+    }
+
+    /// Convert a string to a `NamedMotion` variant
+    pub fn from_str(name: &str) -> Option<Self> {
+        NamedMotion::iter().find(|variant| variant.as_str() == name)
+    }
+}
@@ -0,0 +1,102 @@
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+/// The inputs for the motion tokens - three durations, three curves and a travel distance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotionSet {
+    pub fast_ms: u32,
+    pub base_ms: u32,
+    pub slow_ms: u32,
+    pub ease_standard: Easing,
+    pub ease_enter: Easing,
+    pub ease_exit: Easing,
+    /// How far things slide when they enter/leave, in px.
+    pub distance_px: u32,
+}
+
+#[derive(EnumIter, Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    Linear,
+    Standard,
+    Decelerate,
+    Accelerate,
+    Emphasized,
+    Spring,
+}
+
+impl Easing {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Easing::Linear => "linear",
+            Easing::Standard => "standard",
+            Easing::Decelerate => "decelerate",
+            Easing::Accelerate => "accelerate",
+            Easing::Emphasized => "emphasized",
+            Easing::Spring => "spring",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Easing::Linear => "No easing - progress bars, spinners",
+            Easing::Standard => "Gentle in and out - the all-rounder",
+            Easing::Decelerate => "Fast start, soft landing - things entering",
+            Easing::Accelerate => "Soft start, fast finish - things leaving",
+            Easing::Emphasized => "Long, dramatic tail - hero moments",
+            Easing::Spring => "Slight overshoot - playful UIs",
+        }
+    }
+
+    pub fn curve(&self) -> &'static str {
+        match self {
+            Easing::Linear => "linear",
+            Easing::Standard => "cubic-bezier(0.2, 0, 0, 1)",
+            Easing::Decelerate => "cubic-bezier(0, 0, 0, 1)",
+            Easing::Accelerate => "cubic-bezier(0.3, 0, 1, 1)",
+            Easing::Emphasized => "cubic-bezier(0.05, 0.7, 0.1, 1)",
+            Easing::Spring => "cubic-bezier(0.34, 1.56, 0.64, 1)",
+        }
+    }
+
+    pub fn from_str(name: &str) -> Option<Self> {
+        Easing::iter().find(|variant| variant.as_str() == name)
+    }
+}
+
+/// The keyframes every motion set ships, as `(name, from, to)` declarations.
+///
+/// Enter animations use the individual `scale`/`translate` properties rather than `transform`
+/// so they compose with any positioning transform already on the element (e.g. a centered modal).
+pub const KEYFRAMES: [(&str, &str, &str); 6] = [
+    ("fade-in", "opacity: 0;", "opacity: 1;"),
+    ("fade-out", "opacity: 1;", "opacity: 0;"),
+    (
+        "scale-in",
+        "opacity: 0; scale: 0.96;",
+        "opacity: 1; scale: 1;",
+    ),
+    (
+        "scale-out",
+        "opacity: 1; scale: 1;",
+        "opacity: 0; scale: 0.96;",
+    ),
+    (
+        "slide-up",
+        "opacity: 0; translate: 0 var(--motion-distance);",
+        "opacity: 1; translate: 0 0;",
+    ),
+    (
+        "slide-down",
+        "opacity: 0; translate: 0 calc(var(--motion-distance) * -1);",
+        "opacity: 1; translate: 0 0;",
+    ),
+];
+
+/// Which duration and curve each keyframe's `animate-*` utility uses.
+pub fn keyframe_timing(name: &str) -> (&'static str, &'static str) {
+    match name {
+        "fade-out" | "scale-out" => ("fast", "exit"),
+        "fade-in" => ("fast", "enter"),
+        _ => ("base", "enter"),
+    }
+}
@@ -0,0 +1,105 @@
+use super::transforms::{self, MotionSet};
+use crate::library::client::colors::writes::devano_css_dir;
+use anyhow::Result;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn motion_css_path() -> PathBuf {
+    devano_css_dir().join("motion.css")
+}
+
+/// Renders a `MotionSet` as `--motion-*` custom properties, `dv-*` keyframes and a Tailwind
+/// `@theme` block exposing them as `ease-*` and `animate-*` utilities.
+///
+/// Under `prefers-reduced-motion: reduce` every duration collapses and travel distance is
+/// zeroed, so components that consume the tokens don't need their own media queries.
+pub fn devano_motion_css(motion: &MotionSet) -> String {
+    let mut css_lines = Vec::new();
+
+    css_lines.push(":root {\n".to_string());
+    css_lines.push(format!("\t--motion-duration-fast: {}ms;\n", motion.fast_ms));
+    css_lines.push(format!("\t--motion-duration-base: {}ms;\n", motion.base_ms));
+    css_lines.push(format!("\t--motion-duration-slow: {}ms;\n", motion.slow_ms));
+    css_lines.push(format!(
+        "\t--motion-ease-standard: {};\n",
+        motion.ease_standard.curve()
+    ));
+    css_lines.push(format!(
+        "\t--motion-ease-enter: {};\n",
+        motion.ease_enter.curve()
+    ));
+    css_lines.push(format!(
+        "\t--motion-ease-exit: {};\n",
+        motion.ease_exit.curve()
+    ));
+    css_lines.push(format!("\t--motion-distance: {}px;\n", motion.distance_px));
+    css_lines.push("}\n".to_string());
+
+    css_lines.push("@media (prefers-reduced-motion: reduce) {\n\t:root {\n".to_string());
+    css_lines.push("\t\t--motion-duration-fast: 0.01ms;\n".to_string());
+    css_lines.push("\t\t--motion-duration-base: 0.01ms;\n".to_string());
+    css_lines.push("\t\t--motion-duration-slow: 0.01ms;\n".to_string());
+    css_lines.push("\t\t--motion-distance: 0px;\n".to_string());
+    css_lines.push("\t}\n}\n".to_string());
+
+    for (name, from, to) in transforms::KEYFRAMES {
+        css_lines.push(format!(
+            "@keyframes dv-{} {{\n\tfrom {{ {} }}\n\tto {{ {} }}\n}}\n",
+            name, from, to
+        ));
+    }
+
+    css_lines.push("@theme inline {\n".to_string());
+    css_lines.push("\t--ease-standard: var(--motion-ease-standard);\n".to_string());
+    css_lines.push("\t--ease-enter: var(--motion-ease-enter);\n".to_string());
+    css_lines.push("\t--ease-exit: var(--motion-ease-exit);\n".to_string());
+    for (name, _, _) in transforms::KEYFRAMES {
+        let (duration, ease) = transforms::keyframe_timing(name);
+        css_lines.push(format!(
+            "\t--animate-{}: dv-{} var(--motion-duration-{}) var(--motion-ease-{}) both;\n",
+            name, name, duration, ease
+        ));
+    }
+    css_lines.push("}".to_string());
+
+    css_lines.join("")
+}
+
+pub fn write_devano_motion_css(motion: &MotionSet) -> Result<()> {
+    let file_path = motion_css_path();
+    if let Some(dir_path) = file_path.parent() {
+        fs::create_dir_all(dir_path)?;
+    }
+
+    fs::write(&file_path, devano_motion_css(motion))?;
+
+    println!("Motion written to {:?}", file_path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::client::motion::named_motions::NamedMotion;
+
+    #[test]
+    fn reduced_motion_collapses_durations_and_distance() {
+        let css = devano_motion_css(&NamedMotion::Default.get_motion());
+        let (root, reduced) = css
+            .split_once("@media (prefers-reduced-motion: reduce) {")
+            .unwrap();
+        assert!(root.contains("--motion-duration-base: 200ms;"));
+        assert!(root.contains("--motion-distance: 8px;"));
+
+        let reduced = &reduced[..reduced.find("@keyframes").unwrap()];
+        assert_eq!(
+            reduced,
+            "\n\t:root {\n\
+             \t\t--motion-duration-fast: 0.01ms;\n\
+             \t\t--motion-duration-base: 0.01ms;\n\
+             \t\t--motion-duration-slow: 0.01ms;\n\
+             \t\t--motion-distance: 0px;\n\
+             \t}\n}\n"
+        );
+    }
+}
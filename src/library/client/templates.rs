@@ -0,0 +1,6 @@
+//! Every atom and molecule template as a `UiComponent`, generated by `build.rs` from
+//! `templates/manifest.json`. The TSX itself lives in `templates/`, at the same path it's
+//! installed to under `client/src/devano`.
+use crate::library::client::utils::utils::{ComponentOption, OptionChoice, UiComponent};
+
+include!(concat!(env!("OUT_DIR"), "/templates.rs"));
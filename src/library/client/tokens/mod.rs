@@ -0,0 +1,3 @@
+pub mod named_tokens;
+pub mod transforms;
+pub mod writes;
@@ -0,0 +1,62 @@
+use super::transforms::TokenSet;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(EnumIter, Debug)]
+pub enum NamedTokenSet {
+    Default,
+    Compact,
+    Soft,
+    Sharp,
+}
+
+impl NamedTokenSet {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NamedTokenSet::Default => "default",
+            NamedTokenSet::Compact => "compact",
+            NamedTokenSet::Soft => "soft",
+            NamedTokenSet::Sharp => "sharp",
+        }
+    }
+    // Get the corresponding Description for the enum variant
+    pub fn description(&self) -> &'static str {
+        match self {
+            NamedTokenSet::Default => "4px grid, 6px corners, gentle shadows.",
+            NamedTokenSet::Compact => "3px grid, 4px corners, faint shadows. Dense UIs.",
+            NamedTokenSet::Soft => "4px grid, 12px corners, deep diffuse shadows.",
+            NamedTokenSet::Sharp => "4px grid, square corners, crisp shadows.",
+        }
+    }
+
+    /// Get the corresponding `TokenSet` for the enum variant
+    pub fn get_tokens(&self) -> TokenSet {
+        match self {
+            NamedTokenSet::Default => TokenSet {
+                spacing_unit_px: 4.0,
+                radius_px: 6.0,
+                shadow_strength: 1.0,
+            },
+            NamedTokenSet::Compact => TokenSet {
+                spacing_unit_px: 3.0,
+                radius_px: 4.0,
+                shadow_strength: 0.6,
+            },
+            NamedTokenSet::Soft => TokenSet {
+                spacing_unit_px: 4.0,
+                radius_px: 12.0,
+                shadow_strength: 1.4,
+            },
+            NamedTokenSet::Sharp => TokenSet {
+                spacing_unit_px: 4.0,
+                radius_px: 0.0,
+                shadow_strength: 1.2,
+            },
+        }
+    }
+
+    /// Convert a string to a `NamedTokenSet` variant
+    pub fn from_str(name: &str) -> Option<Self> {
+        NamedTokenSet::iter().find(|variant| variant.as_str() == name)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::library::client::colors::transforms::parse_hex;
+use palette::Srgb;
+
+/// The inputs for the spacing, radius and elevation scales.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenSet {
+    /// Every spacing step is a multiple of this.
+    pub spacing_unit_px: f32,
+    /// The `md` radius - the rest of the radius scale is derived from it.
+    pub radius_px: f32,
+    /// Multiplies the alpha of every shadow layer - `1.0` is the Devano default.
+    pub shadow_strength: f32,
+}
+
+/// A named step of a generated scale, `value` is ready to drop into CSS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenStep {
+    pub name: &'static str,
+    pub value: String,
+}
+
+/// Spacing step names paired with their multiple of `spacing_unit_px`.
+pub const SPACING_STEPS: [(&str, f32); 10] = [
+    ("3xs", 1.0),
+    ("2xs", 2.0),
+    ("xs", 3.0),
+    ("sm", 4.0),
+    ("md", 6.0),
+    ("lg", 8.0),
+    ("xl", 12.0),
+    ("2xl", 16.0),
+    ("3xl", 24.0),
+    ("4xl", 32.0),
+];
+
+/// Radius step names paired with their multiple of `radius_px`.
+pub const RADIUS_STEPS: [(&str, f32); 5] = [
+    ("xs", 0.33),
+    ("sm", 0.66),
+    ("md", 1.0),
+    ("lg", 1.5),
+    ("xl", 2.0),
+];
+
+/// Mobile-first breakpoints as `(name, min-width in rem)` - unprefixed classes are the
+/// phone layout and each prefix widens it from that width up. The same in every preset, so
+/// templates can rely on them.
+pub const BREAKPOINTS: [(&str, f32); 4] = [("sm", 40.0), ("md", 48.0), ("lg", 64.0), ("xl", 80.0)];
+
+/// One shadow layer as `(y offset px, blur px, spread px, alpha)`.
+type ShadowLayer = (f32, f32, f32, f32);
+
+/// Two layers per elevation level: a tight contact shadow and a wider ambient one.
+const ELEVATION_LAYERS: [(&str, [ShadowLayer; 2]); 4] = [
+    ("1", [(1.0, 2.0, 0.0, 0.10), (1.0, 3.0, 0.0, 0.06)]),
+    ("2", [(2.0, 4.0, -1.0, 0.10), (4.0, 8.0, -2.0, 0.08)]),
+    ("3", [(4.0, 6.0, -2.0, 0.10), (12.0, 20.0, -4.0, 0.10)]),
+    ("4", [(8.0, 12.0, -4.0, 0.12), (24.0, 40.0, -8.0, 0.14)]),
+];
+
+/// Dark backgrounds swallow shadows, so dark-mode layers get this much more alpha.
+const DARK_MODE_SHADOW_BOOST: f32 = 3.0;
+
+pub fn make_spacing_steps(tokens: &TokenSet) -> Vec<TokenStep> {
+    SPACING_STEPS
+        .iter()
+        .map(|(name, multiple)| TokenStep {
+            name,
+            value: format!("{}rem", round_to(tokens.spacing_unit_px * multiple / 16.0)),
+        })
+        .collect()
+}
+
+pub fn make_radius_steps(tokens: &TokenSet) -> Vec<TokenStep> {
+    let mut steps: Vec<TokenStep> = RADIUS_STEPS
+        .iter()
+        .map(|(name, multiple)| TokenStep {
+            name,
+            value: format!("{}rem", round_to(tokens.radius_px * multiple / 16.0)),
+        })
+        .collect();
+    steps.push(TokenStep {
+        name: "full",
+        value: "9999px".to_string(),
+    });
+    steps
+}
+
+/// Builds the `--elevation-*` shadows, tinted with `tint_hex` (the palette's darkest `kora`).
+pub fn make_elevation_steps(tokens: &TokenSet, tint_hex: &str, dark_mode: bool) -> Vec<TokenStep> {
+    let tint: Srgb<u8> = parse_hex(tint_hex).into_format();
+    let boost = if dark_mode {
+        DARK_MODE_SHADOW_BOOST
+    } else {
+        1.0
+    };
+    ELEVATION_LAYERS
+        .iter()
+        .map(|(name, layers)| {
+            let shadows: Vec<String> = layers
+                .iter()
+                .map(|(y, blur, spread, alpha)| {
+                    let alpha = (alpha * tokens.shadow_strength * boost).min(1.0);
+                    format!(
+                        "0 {}px {}px {}px rgb({} {} {} / {})",
+                        y,
+                        blur,
+                        spread,
+                        tint.red,
+                        tint.green,
+                        tint.blue,
+                        round_to(alpha)
+                    )
+                })
+                .collect();
+            TokenStep {
+                name,
+                value: shadows.join(", "),
+            }
+        })
+        .collect()
+}
+
+fn round_to(value: f32) -> f32 {
+    (value * 1000.0).round() / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(shadow_strength: f32) -> TokenSet {
+        TokenSet {
+            spacing_unit_px: 4.0,
+            radius_px: 6.0,
+            shadow_strength,
+        }
+    }
+
+    fn value<'a>(steps: &'a [TokenStep], name: &str) -> &'a str {
+        &steps.iter().find(|step| step.name == name).unwrap().value
+    }
+
+    #[test]
+    fn spacing_steps_are_multiples_of_the_unit() {
+        let spacing = make_spacing_steps(&tokens(1.0));
+        assert_eq!(value(&spacing, "3xs"), "0.25rem");
+        assert_eq!(value(&spacing, "md"), "1.5rem");
+        assert_eq!(value(&spacing, "4xl"), "8rem");
+
+        let radius = make_radius_steps(&tokens(1.0));
+        assert_eq!(value(&radius, "md"), "0.375rem");
+        assert_eq!(value(&radius, "full"), "9999px");
+    }
+
+    #[test]
+    fn elevation_steps_are_tinted_and_boosted_in_dark_mode() {
+        let light = make_elevation_steps(&tokens(1.0), "#102030", false);
+        assert_eq!(
+            value(&light, "1"),
+            "0 1px 2px 0px rgb(16 32 48 / 0.1), 0 1px 3px 0px rgb(16 32 48 / 0.06)"
+        );
+        let dark = make_elevation_steps(&tokens(1.0), "#102030", true);
+        assert_eq!(
+            value(&dark, "4"),
+            "0 8px 12px -4px rgb(16 32 48 / 0.36), 0 24px 40px -8px rgb(16 32 48 / 0.42)"
+        );
+    }
+
+    #[test]
+    fn elevation_alpha_never_exceeds_one() {
+        let dark = make_elevation_steps(&tokens(4.0), "#000000", true);
+        assert_eq!(
+            value(&dark, "4"),
+            "0 8px 12px -4px rgb(0 0 0 / 1), 0 24px 40px -8px rgb(0 0 0 / 1)"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use super::transforms::{self, TokenSet, TokenStep};
+use crate::library::client::colors::transforms::DevanoPalette;
+use crate::library::client::colors::writes::devano_css_dir;
+use anyhow::Result;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn tokens_css_path() -> PathBuf {
+    devano_css_dir().join("tokens.css")
+}
+
+/// Tailwind's `shadow-*` names for each `--elevation-*` level.
+const SHADOW_NAMES: [(&str, &str); 4] = [("sm", "1"), ("md", "2"), ("lg", "3"), ("xl", "4")];
+
+/// Renders spacing, radius and elevation as `--space-*`, `--corner-*` and `--elevation-*`
+/// custom properties plus a Tailwind `@theme` block pointing the utilities at them. The
+/// `@theme` block also sets the `sm:`..`xl:` breakpoints - as plain values, since media
+/// queries can't read custom properties.
+///
+/// Shadows are tinted with the palette's darkest `kora` tone in both schemes, with a
+/// `prefers-color-scheme: dark` block that strengthens them like `palette.css` swaps colors.
+pub fn devano_tokens_css(tokens: &TokenSet, palette: &DevanoPalette) -> String {
+    fn generate(prefix: &str, steps: &[TokenStep], indent: &str) -> Vec<String> {
+        steps
+            .iter()
+            .map(|step| format!("{}--{}-{}: {};\n", indent, prefix, step.name, step.value))
+            .collect()
+    }
+
+    let spacing = transforms::make_spacing_steps(tokens);
+    let radius = transforms::make_radius_steps(tokens);
+    let light_elevation = transforms::make_elevation_steps(tokens, &palette.kora.ara, false);
+    let dark_elevation = transforms::make_elevation_steps(tokens, &palette.kora.ara, true);
+
+    let mut css_lines = Vec::new();
+
+    css_lines.push(":root {\n".to_string());
+    css_lines.push(format!(
+        "\t--space-unit: {}rem;\n",
+        tokens.spacing_unit_px / 16.0
+    ));
+    css_lines.extend(generate("space", &spacing, "\t"));
+    css_lines.extend(generate("corner", &radius, "\t"));
+    css_lines.extend(generate("elevation", &light_elevation, "\t"));
+    css_lines.push("}\n".to_string());
+
+    css_lines.push("@media (prefers-color-scheme: dark) {\n\t:root {\n".to_string());
+    css_lines.extend(generate("elevation", &dark_elevation, "\t\t"));
+    css_lines.push("\t}\n}\n".to_string());
+
+    css_lines.push("@theme inline {\n".to_string());
+    css_lines.push("\t--spacing: var(--space-unit);\n".to_string());
+    css_lines.extend(
+        spacing
+            .iter()
+            .map(|step| format!("\t--spacing-{}: var(--space-{});\n", step.name, step.name)),
+    );
+    css_lines.extend(
+        radius
+            .iter()
+            .map(|step| format!("\t--radius-{}: var(--corner-{});\n", step.name, step.name)),
+    );
+    css_lines.extend(
+        SHADOW_NAMES
+            .iter()
+            .map(|(name, level)| format!("\t--shadow-{}: var(--elevation-{});\n", name, level)),
+    );
+    css_lines.extend(
+        transforms::BREAKPOINTS
+            .iter()
+            .map(|(name, rem)| format!("\t--breakpoint-{}: {}rem;\n", name, rem)),
+    );
+    css_lines.push("}".to_string());
+
+    css_lines.join("")
+}
+
+pub fn write_devano_tokens_css(tokens: &TokenSet, palette: &DevanoPalette) -> Result<()> {
+    let file_path = tokens_css_path();
+    if let Some(dir_path) = file_path.parent() {
+        fs::create_dir_all(dir_path)?;
+    }
+
+    fs::write(&file_path, devano_tokens_css(tokens, palette))?;
+
+    println!("Tokens written to {:?}", file_path);
+    Ok(())
+}
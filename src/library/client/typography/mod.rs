@@ -0,0 +1,3 @@
+pub mod named_scales;
+pub mod transforms;
+pub mod writes;
@@ -0,0 +1,88 @@
+use super::transforms::{FontStackPreset, FontStacks, MONO_STACK, Ratio, TypeScale};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(EnumIter, Debug)]
+pub enum NamedTypeScale {
+    Default,
+    Compact,
+    Editorial,
+    Display,
+}
+
+impl NamedTypeScale {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NamedTypeScale::Default => "default",
+            NamedTypeScale::Compact => "compact",
+            NamedTypeScale::Editorial => "editorial",
+            NamedTypeScale::Display => "display",
+        }
+    }
+    // Get the corresponding Description for the enum variant
+    pub fn description(&self) -> &'static str {
+        match self {
+            NamedTypeScale::Default => "16px system sans on a major third. Safe for anything.",
+            NamedTypeScale::Compact => "14px system sans on a major second. Dashboards & admin.",
+            NamedTypeScale::Editorial => {
+                "18px old-style serif headings on a perfect fourth. Blogs."
+            }
+            NamedTypeScale::Display => "16px geometric sans on a perfect fifth. Landing pages.",
+        }
+    }
+
+    /// Get the corresponding `TypeScale` for the enum variant
+    pub fn get_scale(&self) -> TypeScale {
+        match self {
+            NamedTypeScale::Default => TypeScale {
+                base_px: 16.0,
+                ratio: Ratio::MajorThird.value(),
+                body_line_height: 1.5,
+                heading_line_height: 1.2,
+                fonts: FontStacks {
+                    body: FontStackPreset::SystemSans.stack().to_string(),
+                    heading: FontStackPreset::SystemSans.stack().to_string(),
+                    mono: MONO_STACK.to_string(),
+                },
+            },
+            NamedTypeScale::Compact => TypeScale {
+                base_px: 14.0,
+                ratio: Ratio::MajorSecond.value(),
+                body_line_height: 1.45,
+                heading_line_height: 1.25,
+                fonts: FontStacks {
+                    body: FontStackPreset::SystemSans.stack().to_string(),
+                    heading: FontStackPreset::SystemSans.stack().to_string(),
+                    mono: MONO_STACK.to_string(),
+                },
+            },
+            NamedTypeScale::Editorial => TypeScale {
+                base_px: 18.0,
+                ratio: Ratio::PerfectFourth.value(),
+                body_line_height: 1.6,
+                heading_line_height: 1.15,
+                fonts: FontStacks {
+                    body: FontStackPreset::Transitional.stack().to_string(),
+                    heading: FontStackPreset::OldStyle.stack().to_string(),
+                    mono: MONO_STACK.to_string(),
+                },
+            },
+            NamedTypeScale::Display => TypeScale {
+                base_px: 16.0,
+                ratio: Ratio::PerfectFifth.value(),
+                body_line_height: 1.5,
+                heading_line_height: 1.05,
+                fonts: FontStacks {
+                    body: FontStackPreset::Humanist.stack().to_string(),
+                    heading: FontStackPreset::Geometric.stack().to_string(),
+                    mono: MONO_STACK.to_string(),
+                },
+            },
+        }
+    }
+
+    /// Convert a string to a `NamedTypeScale` variant
+    pub fn from_str(name: &str) -> Option<Self> {
+        NamedTypeScale::iter().find(|variant| variant.as_str() == name)
+    }
+}
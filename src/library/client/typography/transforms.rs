@@ -0,0 +1,227 @@
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+/// The inputs for a modular type scale - every step is `base_px * ratio^n`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeScale {
+    pub base_px: f32,
+    pub ratio: f32,
+    pub body_line_height: f32,
+    pub heading_line_height: f32,
+    pub fonts: FontStacks,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontStacks {
+    pub body: String,
+    pub heading: String,
+    pub mono: String,
+}
+
+/// One rendered step of a `TypeScale`, named after the Tailwind `text-*` utility it backs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeStep {
+    pub name: &'static str,
+    pub rem: f32,
+    pub heading: bool,
+}
+
+/// Tailwind's size names, paired with their power of the ratio (`base` is the root).
+pub const STEP_NAMES: [(&str, i32); 10] = [
+    ("xs", -2),
+    ("sm", -1),
+    ("base", 0),
+    ("lg", 1),
+    ("xl", 2),
+    ("2xl", 3),
+    ("3xl", 4),
+    ("4xl", 5),
+    ("5xl", 6),
+    ("6xl", 7),
+];
+
+/// Steps from `xl` up are treated as headings and get the heading line height.
+const FIRST_HEADING_STEP: i32 = 2;
+
+#[derive(EnumIter, Debug, Clone, Copy)]
+pub enum Ratio {
+    MinorSecond,
+    MajorSecond,
+    MinorThird,
+    MajorThird,
+    PerfectFourth,
+    AugmentedFourth,
+    PerfectFifth,
+    Golden,
+}
+
+impl Ratio {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Ratio::MinorSecond => "minor-second",
+            Ratio::MajorSecond => "major-second",
+            Ratio::MinorThird => "minor-third",
+            Ratio::MajorThird => "major-third",
+            Ratio::PerfectFourth => "perfect-fourth",
+            Ratio::AugmentedFourth => "augmented-fourth",
+            Ratio::PerfectFifth => "perfect-fifth",
+            Ratio::Golden => "golden",
+        }
+    }
+
+    pub fn value(&self) -> f32 {
+        match self {
+            Ratio::MinorSecond => 1.067,
+            Ratio::MajorSecond => 1.125,
+            Ratio::MinorThird => 1.2,
+            Ratio::MajorThird => 1.25,
+            Ratio::PerfectFourth => 1.333,
+            Ratio::AugmentedFourth => 1.414,
+            Ratio::PerfectFifth => 1.5,
+            Ratio::Golden => 1.618,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Ratio::MinorSecond => "1.067 - barely-there steps, dense UIs",
+            Ratio::MajorSecond => "1.125 - quiet steps, dashboards",
+            Ratio::MinorThird => "1.2 - balanced, good default for apps",
+            Ratio::MajorThird => "1.25 - clear hierarchy, marketing + apps",
+            Ratio::PerfectFourth => "1.333 - editorial, strong headings",
+            Ratio::AugmentedFourth => "1.414 - dramatic, landing pages",
+            Ratio::PerfectFifth => "1.5 - very dramatic, few heading levels",
+            Ratio::Golden => "1.618 - poster-sized headings",
+        }
+    }
+
+    pub fn from_str(name: &str) -> Option<Self> {
+        Ratio::iter().find(|variant| variant.as_str() == name)
+    }
+}
+
+#[derive(EnumIter, Debug, Clone, Copy)]
+pub enum FontStackPreset {
+    SystemSans,
+    Humanist,
+    Geometric,
+    Transitional,
+    OldStyle,
+}
+
+impl FontStackPreset {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FontStackPreset::SystemSans => "system-sans",
+            FontStackPreset::Humanist => "humanist",
+            FontStackPreset::Geometric => "geometric",
+            FontStackPreset::Transitional => "transitional",
+            FontStackPreset::OldStyle => "old-style",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            FontStackPreset::SystemSans => "The platform UI font - zero downloads",
+            FontStackPreset::Humanist => "Seravek, Gill Sans, Calibri... warm sans-serifs",
+            FontStackPreset::Geometric => "Avenir, Montserrat, Corbel... round sans-serifs",
+            FontStackPreset::Transitional => "Charter, Bitstream Charter, Cambria... sturdy serifs",
+            FontStackPreset::OldStyle => "Iowan Old Style, Palatino, Georgia... bookish serifs",
+        }
+    }
+
+    pub fn stack(&self) -> &'static str {
+        match self {
+            FontStackPreset::SystemSans => {
+                "system-ui, -apple-system, \"Segoe UI\", Roboto, \"Helvetica Neue\", Arial, sans-serif"
+            }
+            FontStackPreset::Humanist => {
+                "Seravek, \"Gill Sans Nova\", Ubuntu, Calibri, \"DejaVu Sans\", source-sans-pro, sans-serif"
+            }
+            FontStackPreset::Geometric => {
+                "Avenir, Montserrat, Corbel, \"URW Gothic\", source-sans-pro, sans-serif"
+            }
+            FontStackPreset::Transitional => {
+                "Charter, \"Bitstream Charter\", \"Sitka Text\", Cambria, serif"
+            }
+            FontStackPreset::OldStyle => {
+                "\"Iowan Old Style\", \"Palatino Linotype\", \"URW Palladio L\", P052, Georgia, serif"
+            }
+        }
+    }
+
+    pub fn from_str(name: &str) -> Option<Self> {
+        FontStackPreset::iter().find(|variant| variant.as_str() == name)
+    }
+}
+
+pub const MONO_STACK: &str = "ui-monospace, \"Cascadia Code\", \"Source Code Pro\", Menlo, Consolas, \"DejaVu Sans Mono\", monospace";
+
+pub fn make_type_steps(scale: &TypeScale) -> Vec<TypeStep> {
+    STEP_NAMES
+        .iter()
+        .map(|(name, power)| TypeStep {
+            name,
+            rem: round_to(scale.base_px * scale.ratio.powi(*power) / 16.0, 3),
+            heading: *power >= FIRST_HEADING_STEP,
+        })
+        .collect()
+}
+
+fn round_to(value: f32, places: i32) -> f32 {
+    let factor = 10f32.powi(places);
+    (value * factor).round() / factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scale(base_px: f32, ratio: f32) -> TypeScale {
+        TypeScale {
+            base_px,
+            ratio,
+            body_line_height: 1.5,
+            heading_line_height: 1.2,
+            fonts: FontStacks {
+                body: FontStackPreset::SystemSans.stack().to_string(),
+                heading: FontStackPreset::SystemSans.stack().to_string(),
+                mono: MONO_STACK.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn steps_follow_the_ratio_from_the_base() {
+        let steps = make_type_steps(&scale(16.0, Ratio::MajorThird.value()));
+        let rems: Vec<(&str, f32)> = steps.iter().map(|step| (step.name, step.rem)).collect();
+        assert_eq!(
+            rems,
+            vec![
+                ("xs", 0.64),
+                ("sm", 0.8),
+                ("base", 1.0),
+                ("lg", 1.25),
+                ("xl", 1.563),
+                ("2xl", 1.953),
+                ("3xl", 2.441),
+                ("4xl", 3.052),
+                ("5xl", 3.815),
+                ("6xl", 4.768),
+            ]
+        );
+    }
+
+    #[test]
+    fn base_step_is_the_base_size_and_headings_start_at_xl() {
+        let steps = make_type_steps(&scale(14.0, Ratio::MajorSecond.value()));
+        let base = steps.iter().find(|step| step.name == "base").unwrap();
+        assert_eq!(base.rem, 0.875);
+        let headings: Vec<&str> = steps
+            .iter()
+            .filter(|step| step.heading)
+            .map(|step| step.name)
+            .collect();
+        assert_eq!(headings, vec!["xl", "2xl", "3xl", "4xl", "5xl", "6xl"]);
+    }
+}
@@ -0,0 +1,68 @@
+use super::transforms::{self, TypeScale};
+use crate::library::client::colors::writes::devano_css_dir;
+use anyhow::Result;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn typography_css_path() -> PathBuf {
+    devano_css_dir().join("typography.css")
+}
+
+/// Renders a `TypeScale` as raw `--type-*` custom properties plus a Tailwind `@theme`
+/// block that points the `text-*`, `font-*` and `leading-*` utilities at them.
+pub fn devano_typography_css(scale: &TypeScale) -> String {
+    let steps = transforms::make_type_steps(scale);
+    let mut css_lines = Vec::new();
+
+    css_lines.push(":root {\n".to_string());
+    css_lines.push(format!("\t--type-font-body: {};\n", scale.fonts.body));
+    css_lines.push(format!("\t--type-font-heading: {};\n", scale.fonts.heading));
+    css_lines.push(format!("\t--type-font-mono: {};\n", scale.fonts.mono));
+    css_lines.push(format!(
+        "\t--type-leading-body: {};\n",
+        scale.body_line_height
+    ));
+    css_lines.push(format!(
+        "\t--type-leading-heading: {};\n",
+        scale.heading_line_height
+    ));
+    css_lines.extend(
+        steps
+            .iter()
+            .map(|step| format!("\t--type-{}: {}rem;\n", step.name, step.rem)),
+    );
+    css_lines.push("}\n".to_string());
+
+    css_lines.push("@theme inline {\n".to_string());
+    css_lines.push("\t--font-sans: var(--type-font-body);\n".to_string());
+    css_lines.push("\t--font-heading: var(--type-font-heading);\n".to_string());
+    css_lines.push("\t--font-mono: var(--type-font-mono);\n".to_string());
+    css_lines.push("\t--leading-body: var(--type-leading-body);\n".to_string());
+    css_lines.push("\t--leading-heading: var(--type-leading-heading);\n".to_string());
+    for step in &steps {
+        let leading = if step.heading { "heading" } else { "body" };
+        css_lines.push(format!(
+            "\t--text-{}: var(--type-{});\n",
+            step.name, step.name
+        ));
+        css_lines.push(format!(
+            "\t--text-{}--line-height: var(--type-leading-{});\n",
+            step.name, leading
+        ));
+    }
+    css_lines.push("}".to_string());
+
+    css_lines.join("")
+}
+
+pub fn write_devano_typography_css(scale: &TypeScale) -> Result<()> {
+    let file_path = typography_css_path();
+    if let Some(dir_path) = file_path.parent() {
+        fs::create_dir_all(dir_path)?;
+    }
+
+    fs::write(&file_path, devano_typography_css(scale))?;
+
+    println!("Typography written to {:?}", file_path);
+    Ok(())
+}
@@ -10,6 +10,7 @@
 /// - `contents`: The contents of the file associated with the UI component.
 /// - `folder_path`: The folder path where the UI component resides.
 /// - `npm_deps`: A list of NPM dependencies required by the UI component.
+/// - `options`: Install-time choices rendered into `{{devano:<key>}}` placeholders in `contents`.
 pub struct UiComponent {
     pub name: &'static str,
     pub description: &'static str,
@@ -19,6 +20,29 @@ pub struct UiComponent {
     pub contents: &'static str,
     pub folder_path: &'static str,
     pub npm_deps: &'static [&'static str],
+    pub options: &'static [ComponentOption],
+}
+
+/// An install-time choice declared in `templates/manifest.json`, e.g. a button's size set.
+///
+/// # Fields
+/// - `key`: The placeholder name - `{{devano:size}}` for `size`.
+/// - `prompt`: The question asked when the component is installed.
+/// - `choices`: What can be picked. The first choice is the default.
+pub struct ComponentOption {
+    pub key: &'static str,
+    pub prompt: &'static str,
+    pub choices: &'static [OptionChoice],
+}
+
+/// # Fields
+/// - `id`: What gets recorded in `devano.json`.
+/// - `description`: Shown next to the id in the prompt.
+/// - `text`: What the placeholder is replaced with.
+pub struct OptionChoice {
+    pub id: &'static str,
+    pub description: &'static str,
+    pub text: &'static str,
 }
 
 /// Represents a component of an API, containing metadata and content information.
@@ -1,39 +1,152 @@
+use crate::library::package_manager;
+use anyhow::{Result, anyhow};
+use semver::{Version, VersionReq};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
-use std::process::Command;
 
-pub fn install_npm_deps(deps: &[&str]) -> std::io::Result<()> {
-    if deps.is_empty() {
-        return Ok(()); // nothing to do
+/// The client package of a Devano monorepo - every component dependency belongs here,
+/// not in the monorepo root.
+pub fn client_package_path() -> &'static Path {
+    Path::new("client")
+}
+
+/// The `package.json` sections that count as "installed".
+const DEPENDENCY_SECTIONS: [&str; 3] = ["dependencies", "devDependencies", "peerDependencies"];
+
+/// A `UiComponent.npm_deps` entry - `clsx`, `zod@^3.23` or `@scope/pkg@~1.2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NpmDep<'a> {
+    pub name: &'a str,
+    pub range: Option<&'a str>,
+}
+
+impl<'a> NpmDep<'a> {
+    pub fn parse(spec: &'a str) -> Self {
+        // skip the first character so a scope's leading `@` isn't mistaken for the range separator
+        match spec.get(1..).and_then(|rest| rest.find('@')) {
+            Some(at) => NpmDep {
+                name: &spec[..at + 1],
+                range: Some(&spec[at + 2..]),
+            },
+            None => NpmDep {
+                name: spec,
+                range: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DepStatus {
+    Missing,
+    Satisfied,
+    /// Installed, but the version in `package.json` falls outside the requested range.
+    Conflict {
+        installed: String,
+    },
+}
+
+/// Collects `name -> version spec` from every dependency section of a `package.json`.
+pub fn installed_deps(package_json: &str) -> Result<HashMap<String, String>> {
+    let manifest: Value = serde_json::from_str(package_json)?;
+    let mut installed = HashMap::new();
+    for section in DEPENDENCY_SECTIONS {
+        if let Some(deps) = manifest.get(section).and_then(Value::as_object) {
+            for (name, spec) in deps {
+                let spec = spec.as_str().unwrap_or_default().to_string();
+                installed.entry(name.clone()).or_insert(spec);
+            }
+        }
+    }
+    Ok(installed)
+}
+
+pub fn dep_status(dep: &NpmDep, installed: &HashMap<String, String>) -> DepStatus {
+    let Some(installed_spec) = installed.get(dep.name) else {
+        return DepStatus::Missing;
+    };
+    let (Some(range), Some(installed_version)) = (dep.range, lowest_version(installed_spec)) else {
+        // no range requested, or something like `workspace:*` / a git url we can't reason about
+        return DepStatus::Satisfied;
+    };
+    match npm_range(range) {
+        Some(req) if !req.matches(&installed_version) => DepStatus::Conflict {
+            installed: installed_spec.clone(),
+        },
+        _ => DepStatus::Satisfied,
     }
+}
 
-    let status = Command::new("pnpm")
-        .args(std::iter::once("add").chain(deps.iter().copied()))
-        .status()?; // executes the command
+/// npm reads a bare version as an exact pin, `semver` reads it as a caret range.
+fn npm_range(range: &str) -> Option<VersionReq> {
+    let range = range.trim();
+    if range.starts_with(|c: char| c.is_ascii_digit()) {
+        VersionReq::parse(&format!("={}", range)).ok()
+    } else {
+        VersionReq::parse(range).ok()
+    }
+}
 
-    if !status.success() {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Failed to install dependencies: {:?}", deps),
-        ));
+/// The lowest version an installed spec like `^3.2.0`, `~1.4` or `3.24.2` allows.
+fn lowest_version(spec: &str) -> Option<Version> {
+    let bare = spec.trim_start_matches(['^', '~', '>', '=', 'v', ' ']);
+    let mut parts: Vec<&str> = bare.split('.').collect();
+    if parts.is_empty() || parts.len() > 3 {
+        return None;
+    }
+    while parts.len() < 3 {
+        parts.push("0");
+    }
+    Version::parse(&parts.join(".")).ok()
+}
+
+pub fn install_npm_deps(deps: &[&str]) -> Result<()> {
+    if deps.is_empty() {
+        return Ok(()); // nothing to do
     }
 
+    package_manager::resolve(client_package_path()).add(deps, client_package_path())?;
+
     println!("✅ Installed: {}", deps.join(", "));
     Ok(())
 }
 
-pub fn check_if_deps_installed(deps: &[&str]) -> std::io::Result<()> {
+/// Installs whichever of `deps` the client package doesn't have yet, in a single
+/// package-manager invocation. Deps that are installed at a version outside the
+/// requested range are reported rather than silently upgraded.
+pub fn check_if_deps_installed(deps: &[&str]) -> Result<()> {
     if deps.is_empty() {
         return Ok(()); // nothing to check
     }
 
-    let file_path = Path::new(".").join("package.json");
-    let contents = fs::read_to_string(&file_path)?;
+    let file_path = client_package_path().join("package.json");
+    let contents = fs::read_to_string(&file_path)
+        .map_err(|e| anyhow!("Could not read {:?}: {}", file_path, e))?;
+    let installed =
+        installed_deps(&contents).map_err(|e| anyhow!("Could not parse {:?}: {}", file_path, e))?;
     let mut missing_deps: Vec<&str> = Vec::new();
+    let mut missing_names: Vec<&str> = Vec::new();
 
-    for dep in deps {
-        if !contents.contains(dep) {
-            missing_deps.push(*dep);
+    for spec in deps {
+        let dep = NpmDep::parse(spec);
+        match dep_status(&dep, &installed) {
+            DepStatus::Missing => {
+                if !missing_names.contains(&dep.name) {
+                    missing_names.push(dep.name);
+                    missing_deps.push(*spec);
+                }
+            }
+            DepStatus::Satisfied => {}
+            DepStatus::Conflict { installed } => {
+                eprintln!(
+                    "⚠️ {} is installed as {} but Devano components expect {}",
+                    dep.name,
+                    installed,
+                    dep.range.unwrap_or_default()
+                );
+            }
         }
     }
 
@@ -46,3 +159,52 @@ pub fn check_if_deps_installed(deps: &[&str]) -> std::io::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKAGE_JSON: &str = r#"{
+        "scripts": { "check": "tsc && zod-check" },
+        "dependencies": { "clsx-extra": "^1.0.0", "zod": "3.24.2", "@solidjs/router": "^0.15.0" },
+        "devDependencies": { "tailwind-merge": "~2.6" }
+    }"#;
+
+    #[test]
+    fn parses_names_and_ranges() {
+        assert_eq!(
+            NpmDep::parse("zod@^3.23"),
+            NpmDep {
+                name: "zod",
+                range: Some("^3.23")
+            }
+        );
+        assert_eq!(
+            NpmDep::parse("@solidjs/router@~0.15"),
+            NpmDep {
+                name: "@solidjs/router",
+                range: Some("~0.15")
+            }
+        );
+        assert_eq!(NpmDep::parse("@solidjs/router").range, None);
+    }
+
+    #[test]
+    fn only_counts_real_dependency_entries() {
+        let installed = installed_deps(PACKAGE_JSON).unwrap();
+        assert_eq!(
+            dep_status(&NpmDep::parse("clsx"), &installed),
+            DepStatus::Missing
+        );
+        assert_eq!(
+            dep_status(&NpmDep::parse("zod@^3.23"), &installed),
+            DepStatus::Satisfied
+        );
+        assert_eq!(
+            dep_status(&NpmDep::parse("tailwind-merge@^3"), &installed),
+            DepStatus::Conflict {
+                installed: "~2.6".to_string()
+            }
+        );
+    }
+}
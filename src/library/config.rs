@@ -0,0 +1,51 @@
+use anyhow::{Result, anyhow};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::PathBuf;
+
+/// Project-level settings, kept next to `client/` and `server/` in the monorepo root.
+///
+/// ```json
+/// {
+///   "registries": ["./registry/index.json", "https://example.com/devano/index.json"],
+///   "options": { "button": { "size": "md" } }
+/// }
+/// ```
+pub fn config_path() -> PathBuf {
+    PathBuf::from("devano.json")
+}
+
+/// The project config, or an empty object when the project doesn't have one yet.
+pub fn read_config() -> Result<Value> {
+    let file_path = config_path();
+    if !file_path.exists() {
+        return Ok(Value::Object(Map::new()));
+    }
+    let contents = fs::read_to_string(&file_path)?;
+    let config: Value = serde_json::from_str(&contents)
+        .map_err(|e| anyhow!("Could not parse {:?}: {}", file_path, e))?;
+    if !config.is_object() {
+        return Err(anyhow!("{:?} should contain a JSON object", file_path));
+    }
+    Ok(config)
+}
+
+pub fn write_config(config: &Value) -> Result<()> {
+    fs::write(config_path(), serde_json::to_string_pretty(config)? + "\n")?;
+    Ok(())
+}
+
+/// Where each extra component registry's index lives - a path, `file://` or `http(s)://` URL.
+pub fn registry_locations(config: &Value) -> Vec<String> {
+    config
+        .get("registries")
+        .and_then(Value::as_array)
+        .map(|registries| {
+            registries
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
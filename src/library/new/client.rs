@@ -1,3 +1,5 @@
+use crate::library::package_manager::PackageManager;
+
 pub struct NewDevanoClientFileInstruction {
     #[allow(dead_code)]
     pub name: &'static str,
@@ -352,7 +354,9 @@ export async function handshake() {
 }
 "#;
 
-pub fn make_files() -> (
+pub fn make_files(
+    package_manager: &'static dyn PackageManager,
+) -> (
     Vec<NewDevanoClientFileInstruction>,
     Vec<NewDevanoClientBashInstruction>,
 ) {
@@ -443,9 +447,9 @@ pub fn make_files() -> (
         },
     ];
     let commands = vec![NewDevanoClientBashInstruction {
-        name: "pnpm_install",
-        command: "pnpm",
-        args: &["install"],
+        name: "install",
+        command: package_manager.command(),
+        args: package_manager.install_args(),
     }];
     (files, commands)
 }
@@ -1,3 +1,5 @@
+use crate::library::package_manager::PackageManager;
+
 pub struct NewDevanoServerFileInstruction {
     #[allow(dead_code)]
     pub name: &'static str,
@@ -331,7 +333,9 @@ export function init() {
 }
 "#;
 
-pub fn make_files() -> (
+pub fn make_files(
+    package_manager: &'static dyn PackageManager,
+) -> (
     Vec<NewDevanoServerFileInstruction>,
     Vec<NewDevanoServerBashInstruction>,
 ) {
@@ -428,9 +432,9 @@ pub fn make_files() -> (
             args: &["init"],
         },
         NewDevanoServerBashInstruction {
-            name: "pnpm/i",
-            command: "pnpm",
-            args: &["install"],
+            name: "install",
+            command: package_manager.command(),
+            args: package_manager.install_args(),
         },
     ];
     (files, commands)
@@ -0,0 +1,220 @@
+use anyhow::{Result, anyhow};
+use clap::ValueEnum;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::OnceLock;
+
+/// A JavaScript package manager Devano can drive, both when scaffolding a project and
+/// when installing component dependencies.
+pub trait PackageManager: Sync {
+    /// The executable, e.g. `pnpm`.
+    fn command(&self) -> &'static str;
+    /// Lockfiles this manager writes - used to detect it in an existing project.
+    fn lockfiles(&self) -> &'static [&'static str];
+    /// Installs everything in `package.json`.
+    fn install_args(&self) -> &'static [&'static str] {
+        &["install"]
+    }
+    /// Adds new packages - followed by the package specs.
+    fn add_args(&self) -> &'static [&'static str] {
+        &["add"]
+    }
+
+    fn add(&self, deps: &[&str], dir: &Path) -> Result<()> {
+        let status = Command::new(self.command())
+            .args(self.add_args().iter().chain(deps))
+            .current_dir(dir)
+            .status()
+            .map_err(|e| anyhow!("Could not run {}: {}", self.command(), e))?;
+        if !status.success() {
+            return Err(anyhow!(
+                "{} failed to install dependencies: {:?}",
+                self.command(),
+                deps
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub struct Pnpm;
+pub struct Npm;
+pub struct Yarn;
+pub struct Bun;
+
+impl PackageManager for Pnpm {
+    fn command(&self) -> &'static str {
+        "pnpm"
+    }
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["pnpm-lock.yaml"]
+    }
+}
+
+impl PackageManager for Npm {
+    fn command(&self) -> &'static str {
+        "npm"
+    }
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["package-lock.json"]
+    }
+    fn add_args(&self) -> &'static [&'static str] {
+        &["install"]
+    }
+}
+
+impl PackageManager for Yarn {
+    fn command(&self) -> &'static str {
+        "yarn"
+    }
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["yarn.lock"]
+    }
+}
+
+impl PackageManager for Bun {
+    fn command(&self) -> &'static str {
+        "bun"
+    }
+    fn lockfiles(&self) -> &'static [&'static str] {
+        &["bun.lock", "bun.lockb"]
+    }
+}
+
+/// The `--pm` flag values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PackageManagers {
+    Pnpm,
+    Npm,
+    Yarn,
+    Bun,
+}
+
+impl PackageManagers {
+    pub fn get(&self) -> &'static dyn PackageManager {
+        match self {
+            PackageManagers::Pnpm => &Pnpm,
+            PackageManagers::Npm => &Npm,
+            PackageManagers::Yarn => &Yarn,
+            PackageManagers::Bun => &Bun,
+        }
+    }
+
+    pub fn from_str(name: &str) -> Option<Self> {
+        Self::value_variants()
+            .iter()
+            .copied()
+            .find(|pm| pm.get().command() == name)
+    }
+}
+
+static OVERRIDE: OnceLock<PackageManagers> = OnceLock::new();
+
+/// Set once from the `--pm` flag; wins over anything detected on disk.
+pub fn set_override(pm: PackageManagers) {
+    let _ = OVERRIDE.set(pm);
+}
+
+/// Reads the manager from `package.json`'s `packageManager` field (`"bun@1.2.0"`).
+pub fn from_manifest(package_json: &str) -> Option<PackageManagers> {
+    let manifest: Value = serde_json::from_str(package_json).ok()?;
+    let field = manifest.get("packageManager")?.as_str()?;
+    let name = field.split('@').next().unwrap_or_default();
+    PackageManagers::from_str(name)
+}
+
+/// Looks for a `packageManager` field or a lockfile in `dir`, then in its parent - a
+/// workspace install keeps the lockfile at the monorepo root, not in `client/`.
+pub fn detect(dir: &Path) -> Option<PackageManagers> {
+    for candidate in dir.ancestors().take(2) {
+        let manifest = fs::read_to_string(candidate.join("package.json")).ok();
+        if let Some(pm) = manifest.as_deref().and_then(from_manifest) {
+            return Some(pm);
+        }
+        let lockfile_match = PackageManagers::value_variants().iter().find(|pm| {
+            pm.get()
+                .lockfiles()
+                .iter()
+                .any(|lockfile| candidate.join(lockfile).exists())
+        });
+        if let Some(pm) = lockfile_match {
+            return Some(*pm);
+        }
+    }
+    None
+}
+
+/// The `--pm` override, else whatever `dir` already uses, else pnpm.
+pub fn resolve(dir: &Path) -> &'static dyn PackageManager {
+    choose(OVERRIDE.get().copied(), dir).get()
+}
+
+fn choose(override_pm: Option<PackageManagers>, dir: &Path) -> PackageManagers {
+    override_pm
+        .or_else(|| detect(dir))
+        .unwrap_or(PackageManagers::Pnpm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::path::PathBuf;
+
+    fn project_with(name: &str, files: &[&str]) -> PathBuf {
+        let dir = env::temp_dir().join(format!("devano-pm-test-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for file in files {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn reads_the_package_manager_field() {
+        assert_eq!(
+            from_manifest(r#"{ "packageManager": "bun@1.2.0" }"#),
+            Some(PackageManagers::Bun)
+        );
+        assert_eq!(
+            from_manifest(r#"{ "packageManager": "yarn@4.1.1+sha512.abc" }"#),
+            Some(PackageManagers::Yarn)
+        );
+        assert_eq!(from_manifest(r#"{ "name": "client" }"#), None);
+    }
+
+    #[test]
+    fn detects_the_manager_from_its_lockfile() {
+        let cases = [
+            ("pnpm-lock.yaml", PackageManagers::Pnpm),
+            ("bun.lockb", PackageManagers::Bun),
+            ("yarn.lock", PackageManagers::Yarn),
+            ("package-lock.json", PackageManagers::Npm),
+        ];
+        for (lockfile, expected) in cases {
+            let dir = project_with(lockfile, &[lockfile]);
+            assert_eq!(detect(&dir), Some(expected), "{}", lockfile);
+        }
+    }
+
+    #[test]
+    fn falls_back_to_pnpm_without_a_lockfile() {
+        let dir = project_with("none", &[]).join("client");
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(detect(&dir), None);
+        assert_eq!(choose(None, &dir), PackageManagers::Pnpm);
+    }
+
+    #[test]
+    fn the_pm_flag_wins_over_the_lockfile() {
+        let dir = project_with("override", &["yarn.lock"]);
+        assert_eq!(choose(None, &dir), PackageManagers::Yarn);
+        assert_eq!(
+            choose(Some(PackageManagers::Bun), &dir),
+            PackageManagers::Bun
+        );
+    }
+}
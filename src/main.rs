@@ -6,6 +6,9 @@ mod library;
 fn main() {
     // Parse command-line arguments into our Cli struct
     let cli = cli::Cli::parse();
+    if let Some(package_manager) = cli.package_manager {
+        library::package_manager::set_override(package_manager);
+    }
 
     // Handle subcommands
     match cli.command {
@@ -28,6 +31,21 @@ fn main() {
                 std::process::exit(1);
             }
         }
+        cli::Commands::Sync(args) => {
+            if let Err(e) =
+                commands::sync::sync(&args.workshop, args.templates.as_deref(), args.dry_run)
+            {
+                eprintln!("Error: failed to sync workshop: {e}");
+                std::process::exit(1);
+            }
+        }
+        cli::Commands::Icons(args) => {
+            let templates = args.templates.as_ref().map(|path| path.as_deref());
+            if let Err(e) = commands::icons::icons(&args.dir, templates) {
+                eprintln!("Error: failed to generate icons: {e}");
+                std::process::exit(1);
+            }
+        }
         cli::Commands::Feature => {
             if let Err(e) = commands::feature::guided_ui() {
                 eprintln!("Error: failed to create app: {e}");
@@ -0,0 +1,67 @@
+import { JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+import { Spinner } from "~/devano/atoms/feedback/Spinner";
+
+interface ButtonProps extends JSX.ButtonHTMLAttributes<HTMLButtonElement> {
+	outline?: boolean;
+	color?: "default" | "ara" | "ene" | "izi";
+	label?: string;
+	loading?: boolean;
+}
+export function Button(props: ButtonProps) {
+	const [l, rest] = splitProps(props, [
+		"outline",
+		"color",
+		"label",
+		"loading",
+		"class",
+		"children",
+		"disabled",
+	]);
+
+	const color = l.color ?? "{{devano:default_color}}";
+
+	let className = cn([
+		"select-none {{devano:size}} font-semibold rounded-md border-[2px] hover:cursor-pointer disabled:opacity-60 disabled:hover:cursor-not-allowed focus:outline-[1px] focus:outline-(--c-a-e) transition-colors duration-(--motion-duration-fast) ease-(--motion-ease-standard)",
+		{
+			"border-(--fg-e) text-(--fg-e) hover:text-(--fg-i) hover:border-(--fg-i)":
+				color == "default" && l?.outline == true,
+			"border-(--fg-i) bg-(--fg-i) text-(--bg-e) hover:bg-(--fg-o) hover:text-(--bg-i) hover:border-(--fg-o)":
+				color == "default" && l?.outline == undefined,
+			"border-(--c-a-e) text-(--c-a-e) hover:text-(--c-a-i) hover:border-(--c-a-i)":
+				color == "ara" && l?.outline == true,
+			"border-(--c-a-e) text-(--bg-i) bg-(--c-a-e) hover:bg-(--c-a-i) hover:text-(--bg-o) hover:border-(--c-a-i)":
+				color == "ara" && l?.outline == undefined,
+			"border-(--c-e-e) text-(--c-e-e) hover:text-(--c-e-i) hover:border-(--c-e-i)":
+				color == "ene" && l?.outline == true,
+			"border-(--c-e-e) text-(--bg-i) bg-(--c-e-e) hover:bg-(--c-e-i) hover:text-(--bg-o) hover:border-(--c-e-i)":
+				color == "ene" && l?.outline == undefined,
+			"border-(--c-i-e) text-(--c-i-e) hover:text-(--c-i-i) hover:border-(--c-i-i)":
+				color == "izi" && l?.outline == true,
+			"border-(--c-i-e) text-(--bg-i) bg-(--c-i-e) hover:bg-(--c-i-i) hover:text-(--bg-o) hover:border-(--c-i-i)":
+				color == "izi" && l?.outline == undefined,
+		},
+		l?.class,
+	]);
+
+	return (
+		<button
+			class={className}
+			disabled={l.disabled || l.loading}
+			aria-busy={l.loading ? true : undefined}
+			{...rest}
+		>
+			{l.loading ? (
+				<span class="inline-flex items-center gap-[6px]">
+					<Spinner
+						size="sm"
+						aria-hidden="true"
+					/>
+					{l?.label ?? l?.children ?? ""}
+				</span>
+			) : (
+				(l?.label ?? l?.children ?? "")
+			)}
+		</button>
+	);
+}
@@ -0,0 +1,112 @@
+import { JSX, For, splitProps, createSignal, createUniqueId } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+export interface AccordionItem {
+	value: string;
+	title: string;
+	content: JSX.Element;
+}
+
+interface AccordionProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	items: AccordionItem[];
+	multiple?: boolean;
+	defaultOpen?: string[];
+	headingLevel?: 2 | 3 | 4 | 5 | 6;
+}
+
+/**
+ * Collapsible sections following the WAI-ARIA accordion pattern: each header is a button
+ * with `aria-expanded`, and Up, Down, Home and End move between headers.
+ *
+ * @props items Required - `{ value, title, content }` for each section
+ * @props multiple Let more than one section be open at a time
+ * @props defaultOpen The values of the sections that start open
+ * @props headingLevel The heading level wrapping each header - defaults to 3
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Accordion(props: AccordionProps) {
+	const [l, rest] = splitProps(props, [
+		"items",
+		"multiple",
+		"defaultOpen",
+		"headingLevel",
+		"class",
+	]);
+	const id = createUniqueId();
+	const [open, setOpen] = createSignal<string[]>(l.defaultOpen ?? []);
+	const headers: HTMLButtonElement[] = [];
+
+	const toggle = (value: string) => {
+		if (open().includes(value)) {
+			setOpen(open().filter((item) => item !== value));
+		} else {
+			setOpen(l.multiple ? [...open(), value] : [value]);
+		}
+	};
+	const onKeyDown = (evt: KeyboardEvent) => {
+		const current = headers.indexOf(document.activeElement as HTMLButtonElement);
+		if (current === -1) return;
+		const next = {
+			ArrowDown: (current + 1) % headers.length,
+			ArrowUp: (current - 1 + headers.length) % headers.length,
+			Home: 0,
+			End: headers.length - 1,
+		}[evt.key];
+		if (next === undefined) return;
+		evt.preventDefault();
+		headers[next]?.focus();
+	};
+
+	return (
+		<div
+			class={cn([
+				"flex flex-col divide-y-[1px] divide-(--fg-a) rounded-[12px] border-[2px] border-(--fg-a)",
+				l?.class,
+			])}
+			onKeyDown={onKeyDown}
+			{...rest}
+		>
+			<For each={l.items}>
+				{(item, index) => (
+					<div>
+						<div
+							role="heading"
+							aria-level={l.headingLevel ?? 3}
+						>
+							<button
+								ref={(element) => (headers[index()] = element)}
+								type="button"
+								id={`${id}-header-${item.value}`}
+								aria-expanded={open().includes(item.value)}
+								aria-controls={`${id}-panel-${item.value}`}
+								class="flex w-full items-center justify-between gap-[12px] px-[12px] py-[10px] text-left font-semibold hover:bg-(--bg-e) hover:cursor-pointer focus-visible:outline-[1px] focus-visible:-outline-offset-[2px] focus-visible:outline-(--c-a-e)"
+								onClick={() => toggle(item.value)}
+							>
+								{item.title}
+								<span
+									aria-hidden="true"
+									class={cn([
+										"transition-transform duration-(--motion-duration-fast)",
+										open().includes(item.value) ? "rotate-180" : "",
+									])}
+								>
+									⌄
+								</span>
+							</button>
+						</div>
+						<div
+							role="region"
+							id={`${id}-panel-${item.value}`}
+							aria-labelledby={`${id}-header-${item.value}`}
+							hidden={!open().includes(item.value)}
+							class="px-[12px] pb-[12px]"
+						>
+							{item.content}
+						</div>
+					</div>
+				)}
+			</For>
+		</div>
+	);
+}
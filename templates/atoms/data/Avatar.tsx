@@ -0,0 +1,66 @@
+import { JSX, Show, splitProps, createSignal } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface AvatarProps extends JSX.HTMLAttributes<HTMLSpanElement> {
+	name: string;
+	src?: string;
+	size?: "sm" | "md" | "lg";
+}
+
+const sizes = {
+	sm: "size-[24px] text-[10px]",
+	md: "size-[40px] text-[14px]",
+	lg: "size-[64px] text-[22px]",
+};
+
+export function initials(name: string): string {
+	const words = name.trim().split(/\s+/).filter(Boolean);
+	if (words.length === 0) return "?";
+	const first = words[0][0];
+	const last = words.length > 1 ? words[words.length - 1][0] : "";
+	return (first + last).toUpperCase();
+}
+
+/**
+ * A user's picture, falling back to their initials when there's no `src` or it fails to load.
+ *
+ * @props name Required - used as the image's alt text and for the initials
+ * @props src The image URL
+ * @props size "sm" | "md" | "lg" - defaults to "md"
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Avatar(props: AvatarProps) {
+	const [l, rest] = splitProps(props, ["name", "src", "size", "class"]);
+	const [failed, setFailed] = createSignal(false);
+	const avatarCn = cn([
+		"inline-flex items-center justify-center shrink-0 overflow-hidden rounded-full bg-(--c-a-a) text-(--c-a-i) font-semibold select-none",
+		sizes[l.size ?? "md"],
+		l?.class,
+	]);
+	return (
+		<span
+			class={avatarCn}
+			{...rest}
+		>
+			<Show
+				when={l.src && !failed()}
+				fallback={
+					<span
+						role="img"
+						aria-label={l.name}
+					>
+						{initials(l.name)}
+					</span>
+				}
+			>
+				<img
+					src={l.src}
+					alt={l.name}
+					class="size-full object-cover"
+					onError={() => setFailed(true)}
+				/>
+			</Show>
+		</span>
+	);
+}
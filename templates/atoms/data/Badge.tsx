@@ -0,0 +1,41 @@
+import { JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface BadgeProps extends JSX.HTMLAttributes<HTMLSpanElement> {
+	color?: "default" | "ara" | "ene" | "izi";
+	outline?: boolean;
+}
+
+/**
+ *
+ * @props color "default" | "ara" | "ene" | "izi" - which palette accent to use
+ * @props outline Border only, no fill
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Badge(props: BadgeProps) {
+	const [l, rest] = splitProps(props, ["color", "outline", "class", "children"]);
+	const color = l.color ?? "default";
+	const badgeCn = cn([
+		"inline-flex items-center gap-[4px] px-[8px] py-[1px] rounded-full border-[1px] text-[12px] font-semibold whitespace-nowrap",
+		{
+			"border-(--fg-e) text-(--fg-e)": color == "default" && l.outline,
+			"border-(--bg-i) bg-(--bg-i) text-(--fg-e)": color == "default" && !l.outline,
+			"border-(--c-a-e) text-(--c-a-e)": color == "ara" && l.outline,
+			"border-(--c-a-a) bg-(--c-a-a) text-(--c-a-i)": color == "ara" && !l.outline,
+			"border-(--c-e-e) text-(--c-e-e)": color == "ene" && l.outline,
+			"border-(--c-e-a) bg-(--c-e-a) text-(--c-e-i)": color == "ene" && !l.outline,
+			"border-(--c-i-e) text-(--c-i-e)": color == "izi" && l.outline,
+			"border-(--c-i-a) bg-(--c-i-a) text-(--c-i-i)": color == "izi" && !l.outline,
+		},
+		l?.class,
+	]);
+	return (
+		<span
+			class={badgeCn}
+			{...rest}
+		>
+			{l.children}
+		</span>
+	);
+}
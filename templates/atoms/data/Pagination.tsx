@@ -0,0 +1,105 @@
+import { JSX, For, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface PaginationProps extends JSX.HTMLAttributes<HTMLElement> {
+	page: number;
+	pageCount: number;
+	onPageChange: (page: number) => void;
+	siblings?: number;
+	label?: string;
+}
+
+/** Page numbers to show around `page`, with `null` for the gaps - pages are 1-based. */
+export function pageRange(
+	page: number,
+	pageCount: number,
+	siblings = 1,
+): (number | null)[] {
+	const pages: (number | null)[] = [];
+	for (let p = 1; p <= pageCount; p++) {
+		const edge = p === 1 || p === pageCount;
+		const near = Math.abs(p - page) <= siblings;
+		if (edge || near) {
+			pages.push(p);
+		} else if (pages[pages.length - 1] !== null) {
+			pages.push(null);
+		}
+	}
+	return pages;
+}
+
+/**
+ *
+ * @props page Required - the current page, starting at 1
+ * @props pageCount Required - how many pages there are
+ * @props onPageChange Required - called with the page to go to
+ * @props siblings How many pages to show either side of the current one - defaults to 1
+ * @props label The navigation landmark's name - defaults to "Pagination"
+ * @returns
+ */
+export function Pagination(props: PaginationProps) {
+	const [l, rest] = splitProps(props, [
+		"page",
+		"pageCount",
+		"onPageChange",
+		"siblings",
+		"label",
+		"class",
+	]);
+	const go = (page: number) =>
+		l.onPageChange(Math.min(Math.max(page, 1), Math.max(l.pageCount, 1)));
+	const itemCn =
+		"min-w-[32px] h-[32px] px-[6px] rounded-[6px] text-[14px] hover:bg-(--bg-i) hover:cursor-pointer disabled:opacity-50 disabled:hover:bg-transparent disabled:hover:cursor-not-allowed focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)";
+
+	return (
+		<nav
+			aria-label={l.label ?? "Pagination"}
+			class={cn(["flex items-center gap-[4px]", l?.class])}
+			{...rest}
+		>
+			<button
+				type="button"
+				class={itemCn}
+				disabled={l.page <= 1}
+				aria-label="Previous page"
+				onClick={() => go(l.page - 1)}
+			>
+				‹
+			</button>
+			<For each={pageRange(l.page, l.pageCount, l.siblings)}>
+				{(page) =>
+					page === null ? (
+						<span
+							class="px-[4px]"
+							aria-hidden="true"
+						>
+							…
+						</span>
+					) : (
+						<button
+							type="button"
+							class={cn([
+								itemCn,
+								page === l.page ? "bg-(--c-a-e) text-(--bg-i) hover:bg-(--c-a-i)" : "",
+							])}
+							aria-label={`Page ${page}`}
+							aria-current={page === l.page ? "page" : undefined}
+							onClick={() => go(page)}
+						>
+							{page}
+						</button>
+					)
+				}
+			</For>
+			<button
+				type="button"
+				class={itemCn}
+				disabled={l.page >= l.pageCount}
+				aria-label="Next page"
+				onClick={() => go(l.page + 1)}
+			>
+				›
+			</button>
+		</nav>
+	);
+}
@@ -0,0 +1,164 @@
+import { JSX, For, Show, splitProps, createMemo, createSignal } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+import { Pagination } from "~/devano/atoms/data/Pagination";
+
+export interface Column<T> {
+	key: string;
+	header: string;
+	/** What to show in the cell - defaults to `row[key]`. */
+	cell?: (row: T) => JSX.Element;
+	/** The value to sort by - the column is sortable when this is set. */
+	sortBy?: (row: T) => string | number;
+	class?: string;
+}
+
+type Sort = { key: string; direction: "ascending" | "descending" };
+
+interface TableProps<T> extends JSX.HTMLAttributes<HTMLDivElement> {
+	columns: Column<T>[];
+	rows: T[];
+	caption: string;
+	pageSize?: number;
+	empty?: JSX.Element;
+}
+
+/**
+ * A table with click-to-sort headers and, when `pageSize` is set, pagination. Sorting and
+ * paging happen in the browser - for server-side data, sort and slice `rows` yourself.
+ *
+ * @props columns Required - `{ key, header, cell?, sortBy? }` for each column
+ * @props rows Required - the data
+ * @props caption Required - what the table shows; visually hidden, read by screen readers
+ * @props pageSize Rows per page - everything on one page when left out
+ * @props empty What to show when there are no rows
+ * @props class Extend/override the wrapper's styles
+ * @returns
+ */
+export function Table<T>(props: TableProps<T>) {
+	const [l, rest] = splitProps(props, [
+		"columns",
+		"rows",
+		"caption",
+		"pageSize",
+		"empty",
+		"class",
+	]);
+	const [sort, setSort] = createSignal<Sort | null>(null);
+	const [page, setPage] = createSignal(1);
+
+	const sorted = createMemo(() => {
+		const current = sort();
+		const column = l.columns.find((column) => column.key === current?.key);
+		if (!current || !column?.sortBy) return l.rows;
+		const by = column.sortBy;
+		const direction = current.direction === "ascending" ? 1 : -1;
+		return [...l.rows].sort((a, b) => {
+			const [x, y] = [by(a), by(b)];
+			return (x < y ? -1 : x > y ? 1 : 0) * direction;
+		});
+	});
+	const pageCount = () =>
+		l.pageSize ? Math.max(1, Math.ceil(sorted().length / l.pageSize)) : 1;
+	const visible = () => {
+		if (!l.pageSize) return sorted();
+		const current = Math.min(page(), pageCount());
+		return sorted().slice((current - 1) * l.pageSize, current * l.pageSize);
+	};
+
+	const toggleSort = (key: string) => {
+		const current = sort();
+		setSort(
+			current?.key === key && current.direction === "ascending"
+				? { key, direction: "descending" }
+				: { key, direction: "ascending" },
+		);
+		setPage(1);
+	};
+
+	return (
+		<div
+			class={cn(["flex flex-col gap-[12px]", l?.class])}
+			{...rest}
+		>
+			<div class="overflow-x-auto rounded-[12px] border-[2px] border-(--fg-a)">
+				<table class="w-full text-left text-[14px] border-collapse">
+					<caption class="sr-only">{l.caption}</caption>
+					<thead class="bg-(--bg-e)">
+						<tr>
+							<For each={l.columns}>
+								{(column) => (
+									<th
+										scope="col"
+										class={cn(["px-[12px] py-[8px] font-semibold", column.class])}
+										aria-sort={
+											sort()?.key === column.key ? sort()?.direction : undefined
+										}
+									>
+										<Show
+											when={column.sortBy}
+											fallback={column.header}
+										>
+											<button
+												type="button"
+												class="inline-flex items-center gap-[4px] hover:cursor-pointer focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)"
+												onClick={() => toggleSort(column.key)}
+											>
+												{column.header}
+												<span aria-hidden="true">
+													{sort()?.key !== column.key
+														? "↕"
+														: sort()?.direction === "ascending"
+															? "↑"
+															: "↓"}
+												</span>
+											</button>
+										</Show>
+									</th>
+								)}
+							</For>
+						</tr>
+					</thead>
+					<tbody>
+						<Show
+							when={visible().length > 0}
+							fallback={
+								<tr>
+									<td
+										colSpan={l.columns.length}
+										class="px-[12px] py-[24px] text-center"
+									>
+										{l.empty ?? "Nothing to show"}
+									</td>
+								</tr>
+							}
+						>
+							<For each={visible()}>
+								{(row) => (
+									<tr class="border-t-[1px] border-(--fg-a) hover:bg-(--bg-e)">
+										<For each={l.columns}>
+											{(column) => (
+												<td class={cn(["px-[12px] py-[8px]", column.class])}>
+													{column.cell
+														? column.cell(row)
+														: String((row as Record<string, unknown>)[column.key] ?? "")}
+												</td>
+											)}
+										</For>
+									</tr>
+								)}
+							</For>
+						</Show>
+					</tbody>
+				</table>
+			</div>
+			<Show when={pageCount() > 1}>
+				<Pagination
+					class="self-end"
+					page={Math.min(page(), pageCount())}
+					pageCount={pageCount()}
+					onPageChange={setPage}
+				/>
+			</Show>
+		</div>
+	);
+}
@@ -0,0 +1,114 @@
+import { JSX, For, splitProps, createSignal, createUniqueId } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+export interface TabItem {
+	value: string;
+	label: string;
+	content: JSX.Element;
+	disabled?: boolean;
+}
+
+interface TabsProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	items: TabItem[];
+	label: string;
+	defaultValue?: string;
+	onChange?: (value: string) => void;
+}
+
+/**
+ * Tabs following the WAI-ARIA tabs pattern: only the selected tab is in the tab order, Left
+ * and Right (wrapping), Home and End move between tabs and select them.
+ *
+ * @props items Required - `{ value, label, content }` for each tab
+ * @props label Required - the tab list's accessible name
+ * @props defaultValue Which tab starts selected - defaults to the first enabled one
+ * @props onChange Called with the newly selected tab's value
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Tabs(props: TabsProps) {
+	const [l, rest] = splitProps(props, [
+		"items",
+		"label",
+		"defaultValue",
+		"onChange",
+		"class",
+	]);
+	const id = createUniqueId();
+	const enabled = () => l.items.filter((item) => !item.disabled);
+	const [selected, setSelected] = createSignal(
+		l.defaultValue ?? enabled()[0]?.value,
+	);
+	const tabs: Record<string, HTMLButtonElement> = {};
+
+	const select = (value: string) => {
+		setSelected(value);
+		tabs[value]?.focus();
+		l.onChange?.(value);
+	};
+	const onKeyDown = (evt: KeyboardEvent) => {
+		const items = enabled();
+		const current = items.findIndex((item) => item.value === selected());
+		const next = {
+			ArrowRight: (current + 1) % items.length,
+			ArrowLeft: (current - 1 + items.length) % items.length,
+			Home: 0,
+			End: items.length - 1,
+		}[evt.key];
+		if (next === undefined) return;
+		evt.preventDefault();
+		select(items[next].value);
+	};
+
+	return (
+		<div
+			class={cn(["flex flex-col gap-[12px]", l?.class])}
+			{...rest}
+		>
+			<div
+				role="tablist"
+				aria-label={l.label}
+				class="flex gap-[4px] overflow-x-auto border-b-[2px] border-(--fg-a)"
+				onKeyDown={onKeyDown}
+			>
+				<For each={l.items}>
+					{(item) => (
+						<button
+							ref={(element) => (tabs[item.value] = element)}
+							type="button"
+							role="tab"
+							id={`${id}-tab-${item.value}`}
+							aria-controls={`${id}-panel-${item.value}`}
+							aria-selected={selected() === item.value}
+							tabindex={selected() === item.value ? 0 : -1}
+							disabled={item.disabled}
+							class={cn([
+								"-mb-[2px] px-[12px] py-[6px] whitespace-nowrap border-b-[2px] border-transparent hover:cursor-pointer hover:text-(--fg-i) disabled:opacity-50 disabled:hover:cursor-not-allowed focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)",
+								selected() === item.value
+									? "border-(--c-a-e) text-(--c-a-e) font-semibold"
+									: "text-(--fg-e)",
+							])}
+							onClick={() => select(item.value)}
+						>
+							{item.label}
+						</button>
+					)}
+				</For>
+			</div>
+			<For each={l.items}>
+				{(item) => (
+					<div
+						role="tabpanel"
+						id={`${id}-panel-${item.value}`}
+						aria-labelledby={`${id}-tab-${item.value}`}
+						tabindex="0"
+						hidden={selected() !== item.value}
+						class="focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)"
+					>
+						{item.content}
+					</div>
+				)}
+			</For>
+		</div>
+	);
+}
@@ -0,0 +1,67 @@
+import { JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface ProgressProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	value: number;
+	max?: number;
+	label: string;
+	showValue?: boolean;
+	color?: "default" | "ara" | "ene" | "izi";
+}
+
+const fills = {
+	default: "bg-(--fg-i)",
+	ara: "bg-(--c-a-e)",
+	ene: "bg-(--c-e-e)",
+	izi: "bg-(--c-i-e)",
+};
+
+/**
+ * A determinate progress bar - for work with an unknown end, use a Spinner.
+ *
+ * @props value Required - how far along, from 0 to `max`
+ * @props max Defaults to 100
+ * @props label Required - what's progressing, for screen readers and the visible caption
+ * @props showValue Show the percentage next to the label
+ * @props class Extend/override the track's styles
+ * @returns
+ */
+export function Progress(props: ProgressProps) {
+	const [l, rest] = splitProps(props, [
+		"value",
+		"max",
+		"label",
+		"showValue",
+		"color",
+		"class",
+	]);
+	const max = () => l.max ?? 100;
+	const percent = () =>
+		Math.round((Math.min(Math.max(l.value, 0), max()) / max()) * 100);
+
+	return (
+		<div class="flex flex-col gap-[2px]">
+			<div class="flex justify-between text-[14px] select-none">
+				<span>{l.label}</span>
+				{l.showValue && <span>{percent()}%</span>}
+			</div>
+			<div
+				role="progressbar"
+				aria-label={l.label}
+				aria-valuemin={0}
+				aria-valuemax={max()}
+				aria-valuenow={l.value}
+				class={cn(["h-[8px] w-full overflow-hidden rounded-full bg-(--bg-i)", l?.class])}
+				{...rest}
+			>
+				<div
+					class={cn([
+						"h-full rounded-full transition-[width] duration-(--motion-duration-base) ease-(--motion-ease-standard)",
+						fills[l.color ?? "default"],
+					])}
+					style={{ width: `${percent()}%` }}
+				/>
+			</div>
+		</div>
+	);
+}
@@ -0,0 +1,58 @@
+import { JSX, For, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface SkeletonProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	shape?: "text" | "block" | "circle";
+	lines?: number;
+}
+
+/**
+ * Placeholder blocks shown while content loads. Hidden from screen readers - announce the
+ * loading state once, on the container (`aria-busy="true"`), instead.
+ *
+ * @props shape "text" (default) | "block" | "circle" - size blocks and circles with `class`
+ * @props lines For "text", how many lines to draw - the last one is shorter
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Skeleton(props: SkeletonProps) {
+	const [l, rest] = splitProps(props, ["shape", "lines", "class"]);
+	const shape = l.shape ?? "text";
+	const skeletonCn = cn([
+		"bg-(--bg-i) animate-pulse motion-reduce:animate-none",
+		{
+			"h-[1em] rounded-[4px]": shape === "text",
+			"h-[96px] rounded-[12px]": shape === "block",
+			"size-[40px] rounded-full": shape === "circle",
+		},
+		l?.class,
+	]);
+
+	if (shape !== "text" || (l.lines ?? 1) <= 1) {
+		return (
+			<div
+				class={skeletonCn}
+				aria-hidden="true"
+				{...rest}
+			/>
+		);
+	}
+	return (
+		<div
+			class="flex flex-col gap-[6px]"
+			aria-hidden="true"
+			{...rest}
+		>
+			<For each={Array.from({ length: l.lines ?? 1 })}>
+				{(_, index) => (
+					<div
+						class={cn([
+							skeletonCn,
+							index() === (l.lines ?? 1) - 1 ? "w-[60%]" : "w-full",
+						])}
+					/>
+				)}
+			</For>
+		</div>
+	);
+}
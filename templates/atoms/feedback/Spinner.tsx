@@ -0,0 +1,54 @@
+import { JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface SpinnerProps extends JSX.SvgSVGAttributes<SVGSVGElement> {
+	size?: "sm" | "md" | "lg";
+	label?: string;
+}
+
+const sizes = {
+	sm: "size-[14px]",
+	md: "size-[20px]",
+	lg: "size-[32px]",
+};
+
+/**
+ *
+ * @props size "sm" | "md" | "lg" - defaults to "md"
+ * @props label What's loading, for screen readers - defaults to "Loading"
+ * @props class Extend/override default styles - the spinner uses `currentColor`
+ * @returns
+ */
+export function Spinner(props: SpinnerProps) {
+	const [l, rest] = splitProps(props, ["size", "label", "class"]);
+	const spinnerCn = cn([
+		"animate-spin shrink-0 motion-reduce:animate-none",
+		sizes[l.size ?? "md"],
+		l?.class,
+	]);
+	return (
+		<svg
+			class={spinnerCn}
+			viewBox="0 0 24 24"
+			fill="none"
+			role="status"
+			aria-label={l.label ?? "Loading"}
+			{...rest}
+		>
+			<circle
+				cx="12"
+				cy="12"
+				r="10"
+				stroke="currentColor"
+				stroke-width="3"
+				opacity="0.25"
+			/>
+			<path
+				d="M22 12a10 10 0 0 0-10-10"
+				stroke="currentColor"
+				stroke-width="3"
+				stroke-linecap="round"
+			/>
+		</svg>
+	);
+}
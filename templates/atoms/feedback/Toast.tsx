@@ -0,0 +1,195 @@
+import {
+	For,
+	createContext,
+	createSignal,
+	onCleanup,
+	onMount,
+	useContext,
+} from "solid-js";
+import { Portal } from "solid-js/web";
+import type { ParentProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+export type ToastVariant = "default" | "ara" | "ene" | "izi";
+
+export interface ToastOptions {
+	message: string;
+	title?: string;
+	variant?: ToastVariant;
+	/** Milliseconds before it dismisses itself - `0` keeps it until closed. */
+	duration?: number;
+}
+
+interface Toast extends Required<Omit<ToastOptions, "title">> {
+	id: number;
+	title?: string;
+}
+
+type ToastContextType = {
+	show: (options: ToastOptions) => number;
+	success: (message: string, title?: string) => number;
+	error: (message: string, title?: string) => number;
+	info: (message: string, title?: string) => number;
+	dismiss: (id: number) => void;
+};
+
+const ToastContext = createContext<ToastContextType>();
+
+const variants: Record<ToastVariant, string> = {
+	default: "border-(--fg-e) bg-(--bg-e) text-(--fg-e)",
+	ara: "border-(--c-a-e) bg-(--c-a-a) text-(--c-a-i)",
+	ene: "border-(--c-e-e) bg-(--c-e-a) text-(--c-e-i)",
+	izi: "border-(--c-i-e) bg-(--c-i-a) text-(--c-i-i)",
+};
+
+interface ToastProviderProps extends ParentProps {
+	/** How many toasts show at once - the rest wait their turn. Defaults to 3. */
+	max?: number;
+	/** Default auto-dismiss time in milliseconds. Defaults to 5000. */
+	duration?: number;
+}
+
+/**
+ * Holds the toast queue for everything inside it and renders the visible toasts in a
+ * portal. Errors are announced assertively (`role="alert"`), everything else politely
+ * (`role="status"`).
+ *
+ * @props max How many toasts are stacked on screen at once
+ * @props duration Default auto-dismiss time, in milliseconds
+ * @returns
+ */
+export function ToastProvider(props: ToastProviderProps) {
+	const [toasts, setToasts] = createSignal<Toast[]>([]);
+	let nextId = 0;
+
+	const dismiss = (id: number) =>
+		setToasts(toasts().filter((toast) => toast.id !== id));
+	const show = (options: ToastOptions) => {
+		const id = nextId++;
+		setToasts([
+			...toasts(),
+			{
+				id,
+				title: options.title,
+				message: options.message,
+				variant: options.variant ?? "default",
+				duration: options.duration ?? props.duration ?? 5000,
+			},
+		]);
+		return id;
+	};
+	const toast: ToastContextType = {
+		show,
+		success: (message, title) => show({ message, title, variant: "izi" }),
+		error: (message, title) => show({ message, title, variant: "ene" }),
+		info: (message, title) => show({ message, title, variant: "ara" }),
+		dismiss,
+	};
+
+	const visible = () => toasts().slice(0, props.max ?? 3);
+	const regionCn =
+		"fixed bottom-[12px] right-[12px] left-[12px] sm:left-auto z-50 flex flex-col gap-[6px] sm:w-[360px]";
+
+	return (
+		<ToastContext.Provider value={toast}>
+			{props.children}
+			<Portal>
+				<div
+					role="status"
+					aria-live="polite"
+					class={regionCn}
+				>
+					<For each={visible().filter((toast) => toast.variant !== "ene")}>
+						{(toast) => (
+							<ToastItem
+								toast={toast}
+								dismiss={dismiss}
+							/>
+						)}
+					</For>
+				</div>
+				<div
+					role="alert"
+					aria-live="assertive"
+					class={cn([regionCn, "bottom-auto top-[12px]"])}
+				>
+					<For each={visible().filter((toast) => toast.variant === "ene")}>
+						{(toast) => (
+							<ToastItem
+								toast={toast}
+								dismiss={dismiss}
+							/>
+						)}
+					</For>
+				</div>
+			</Portal>
+		</ToastContext.Provider>
+	);
+}
+
+function ToastItem(props: { toast: Toast; dismiss: (id: number) => void }) {
+	// the timer starts when the toast is shown, not when it's queued, and pauses on hover/focus
+	let timer: ReturnType<typeof setTimeout> | undefined;
+	let remaining = props.toast.duration;
+	let startedAt = 0;
+	const start = () => {
+		if (remaining <= 0) return;
+		startedAt = Date.now();
+		timer = setTimeout(() => props.dismiss(props.toast.id), remaining);
+	};
+	const pause = () => {
+		if (timer === undefined) return;
+		clearTimeout(timer);
+		timer = undefined;
+		remaining -= Date.now() - startedAt;
+	};
+	onMount(start);
+	onCleanup(() => clearTimeout(timer));
+
+	const toastCn = cn([
+		"flex items-start gap-[12px] px-[12px] py-[8px] rounded-[8px] border-[2px] animate-slide-up",
+		variants[props.toast.variant],
+	]);
+
+	return (
+		<div
+			class={toastCn}
+			onMouseEnter={pause}
+			onMouseLeave={start}
+			onFocusIn={pause}
+			onFocusOut={start}
+		>
+			<div class="flex flex-col flex-1 gap-[2px]">
+				{props.toast.title && (
+					<span class="font-semibold">{props.toast.title}</span>
+				)}
+				<span class="text-[14px]">{props.toast.message}</span>
+			</div>
+			<button
+				type="button"
+				aria-label="Dismiss notification"
+				class="px-[4px] rounded-[4px] hover:cursor-pointer focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)"
+				onClick={() => props.dismiss(props.toast.id)}
+			>
+				×
+			</button>
+		</div>
+	);
+}
+
+/**
+ * The toast queue from the nearest `ToastProvider`.
+ *
+ * ```tsx
+ * const toast = useToast();
+ * toast.success("Saved!");
+ * toast.show({ title: "Heads up", message: "...", variant: "ara", duration: 0 });
+ * ```
+ */
+export function useToast(): ToastContextType {
+	const context = useContext(ToastContext);
+	if (!context) {
+		throw new Error("useToast must be used inside a <ToastProvider>");
+	}
+	return context;
+}
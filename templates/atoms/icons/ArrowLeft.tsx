@@ -0,0 +1,22 @@
+// Generated by `devano icons` from arrow-left.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function ArrowLeft(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<path d="M19 12H5" />
+			<path d="M11 6l-6 6 6 6" />
+		</Icon>
+	);
+}
+
+export default ArrowLeft;
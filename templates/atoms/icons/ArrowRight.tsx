@@ -0,0 +1,22 @@
+// Generated by `devano icons` from arrow-right.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function ArrowRight(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<path d="M5 12h14" />
+			<path d="M13 6l6 6-6 6" />
+		</Icon>
+	);
+}
+
+export default ArrowRight;
@@ -0,0 +1,21 @@
+// Generated by `devano icons` from check.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function Check(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<path d="M5 12.5l4.5 4.5L19 7" />
+		</Icon>
+	);
+}
+
+export default Check;
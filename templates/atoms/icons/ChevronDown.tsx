@@ -0,0 +1,21 @@
+// Generated by `devano icons` from chevron-down.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function ChevronDown(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<path d="M6 9l6 6 6-6" />
+		</Icon>
+	);
+}
+
+export default ChevronDown;
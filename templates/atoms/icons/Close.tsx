@@ -0,0 +1,22 @@
+// Generated by `devano icons` from close.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function Close(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<path d="M6 6l12 12" />
+			<path d="M18 6L6 18" />
+		</Icon>
+	);
+}
+
+export default Close;
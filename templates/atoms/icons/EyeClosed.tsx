@@ -0,0 +1,17 @@
+// Generated by `devano icons` from eye-closed.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function EyeClosed(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 100 60"
+			fill="currentColor"
+			{...props}
+		>
+			<path d="M90 13.5C91.3807 13.5 92.5 14.6193 92.5 16C92.5 23.5926 89.7805 31.7988 82.9424 38.0898C76.1075 44.3779 65.4361 48.5 50 48.5C34.5639 48.5 23.8925 44.3779 17.0576 38.0898C10.2195 31.7988 7.5 23.5926 7.5 16C7.5 14.6193 8.61929 13.5 10 13.5C11.3807 13.5 12.5 14.6193 12.5 16C12.5 22.4073 14.7806 29.2012 20.4424 34.4102C26.1075 39.622 35.4362 43.5 50 43.5C64.5638 43.5 73.8925 39.622 79.5576 34.4102C85.2194 29.2012 87.5 22.4073 87.5 16C87.5 14.6193 88.6193 13.5 90 13.5Z" />
+		</Icon>
+	);
+}
+
+export default EyeClosed;
@@ -0,0 +1,37 @@
+import { JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+export interface IconProps extends JSX.SvgSVGAttributes<SVGSVGElement> {
+	size?: number | string;
+	label?: string;
+}
+
+/**
+ * The `<svg>` every Devano icon renders through, so they all take the same props.
+ *
+ * @props size Width and height - a number of pixels or any CSS length. Defaults to `1em`, so icons follow the text size
+ * @props label What the icon means when it stands on its own. Without one, the icon is treated as decoration and hidden from screen readers
+ * @props class Extend/override default styles - icons draw in `currentColor`, so set the text color
+ * @returns
+ */
+export function Icon(props: IconProps) {
+	const [l, rest] = splitProps(props, ["size", "label", "class", "children"]);
+	const size = () =>
+		typeof l.size === "number" ? `${l.size}px` : (l.size ?? "1em");
+	return (
+		<svg
+			xmlns="http://www.w3.org/2000/svg"
+			width={size()}
+			height={size()}
+			class={cn(["shrink-0", l?.class])}
+			role={l.label ? "img" : undefined}
+			aria-label={l.label}
+			aria-hidden={l.label ? undefined : true}
+			{...rest}
+		>
+			{l.children}
+		</svg>
+	);
+}
+
+export default Icon;
@@ -0,0 +1,23 @@
+// Generated by `devano icons` from info.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function Info(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<circle cx="12" cy="12" r="9" />
+			<path d="M12 11v5" />
+			<path d="M12 8h.01" />
+		</Icon>
+	);
+}
+
+export default Info;
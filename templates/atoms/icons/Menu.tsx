@@ -0,0 +1,23 @@
+// Generated by `devano icons` from menu.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function Menu(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<path d="M4 6h16" />
+			<path d="M4 12h16" />
+			<path d="M4 18h16" />
+		</Icon>
+	);
+}
+
+export default Menu;
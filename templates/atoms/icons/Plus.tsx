@@ -0,0 +1,22 @@
+// Generated by `devano icons` from plus.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function Plus(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<path d="M12 5v14" />
+			<path d="M5 12h14" />
+		</Icon>
+	);
+}
+
+export default Plus;
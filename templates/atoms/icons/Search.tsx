@@ -0,0 +1,22 @@
+// Generated by `devano icons` from search.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function Search(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<circle cx="11" cy="11" r="7" />
+			<path d="M20 20l-4-4" />
+		</Icon>
+	);
+}
+
+export default Search;
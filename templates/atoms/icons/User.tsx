@@ -0,0 +1,22 @@
+// Generated by `devano icons` from user.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function User(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<circle cx="12" cy="8" r="4" />
+			<path d="M4 20c0-3.5 3.5-6 8-6s8 2.5 8 6" />
+		</Icon>
+	);
+}
+
+export default User;
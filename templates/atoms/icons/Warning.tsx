@@ -0,0 +1,23 @@
+// Generated by `devano icons` from warning.svg
+import { Icon } from "~/devano/atoms/icons/Icon";
+import type { IconProps } from "~/devano/atoms/icons/Icon";
+
+export function Warning(props: IconProps) {
+	return (
+		<Icon
+			viewBox="0 0 24 24"
+			fill="none"
+			stroke="currentColor"
+			stroke-width="2"
+			stroke-linecap="round"
+			stroke-linejoin="round"
+			{...props}
+		>
+			<path d="M12 3.5L2.5 20h19L12 3.5z" />
+			<path d="M12 10v4" />
+			<path d="M12 17h.01" />
+		</Icon>
+	);
+}
+
+export default Warning;
@@ -0,0 +1,77 @@
+import { JSX, splitProps, createSignal } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+import type { Accessor, Setter } from "solid-js";
+import { ZodType, ZodError } from "zod";
+
+interface ExtendedCheckboxProps
+	extends JSX.InputHTMLAttributes<HTMLInputElement> {
+	label: string;
+	get: Accessor<boolean>;
+	set: Setter<boolean>;
+	validationSchema?: ZodType<any>;
+	onValidationError?: (error: string) => void;
+}
+
+export function Checkbox(props: ExtendedCheckboxProps) {
+	const [l, most] = splitProps(props, [
+		"class",
+		"label",
+		"get",
+		"set",
+		"validationSchema",
+		"onValidationError",
+	]);
+	const [unused, rest] = splitProps(most, [
+		"type",
+		"checked",
+		"on:change",
+		"onChange",
+		"onchange",
+	]);
+
+	const [error, set_error] = createSignal<string | null>(null);
+
+	let className = () =>
+		cn([
+			"size-[18px] shrink-0 accent-(--c-a-e) rounded-[4px] border-[2px] border-(--fg-e) hover:border-(--fg-i) focus-visible:outline-[1px] focus-visible:outline-offset-[2px] focus-visible:outline-(--c-a-e)",
+			l?.class,
+			error() ? "border-(--c-e-e) hover:border-(--c-e-i)" : "",
+		]);
+
+	const validate = (checked: boolean) => {
+		if (l.validationSchema) {
+			try {
+				l.validationSchema.parse(checked);
+				set_error(null);
+			} catch (err) {
+				if (err instanceof ZodError) {
+					const errorMessage = err.errors[0]?.message || "Invalid Input";
+					set_error(errorMessage);
+					if (l.onValidationError) {
+						l.onValidationError(errorMessage);
+					}
+				}
+			}
+		}
+	};
+
+	return (
+		<div class="flex flex-col gap-[2px]">
+			<label class="flex items-center gap-[6px] text-[14px] select-none">
+				<input
+					type="checkbox"
+					class={className()}
+					checked={l.get()}
+					aria-invalid={error() ? true : undefined}
+					onChange={(evt) => {
+						l.set(evt.currentTarget.checked);
+						validate(evt.currentTarget.checked);
+					}}
+					{...rest}
+				/>
+				{l.label}
+			</label>
+			{error() && <span class="text-(--c-e-e) text-[12px]">{error()}</span>}
+		</div>
+	);
+}
@@ -0,0 +1,206 @@
+import { JSX, Match, Switch, createSignal, splitProps } from "solid-js";
+import { createStore } from "solid-js/store";
+import { cn } from "~/devano/utils/cn";
+import { TextInput } from "~/devano/atoms/inputs/TextInput";
+import { PasswordInput } from "~/devano/atoms/inputs/PasswordInput";
+import type { Accessor } from "solid-js";
+import type { AnyZodObject, ZodEffects, ZodTypeAny, z } from "zod";
+
+// `z.object(...)`, or one wrapped in `.refine(...)` for cross-field checks like confirming a password
+type FormSchema = AnyZodObject | ZodEffects<AnyZodObject>;
+type Values<S extends FormSchema> = z.infer<S>;
+type FieldName<S extends FormSchema> = keyof Values<S> & string;
+type Errors<S extends FormSchema> = Partial<Record<FieldName<S>, string>>;
+
+interface CreateFormOptions<S extends FormSchema> {
+	schema: S;
+	initial: Values<S>;
+	onSubmit: (values: Values<S>) => Promise<void> | void;
+}
+
+export interface FormApi<S extends FormSchema> {
+	values: Values<S>;
+	errors: Errors<S>;
+	/** The first error, for a form-level `ErrorMessage` */
+	error: Accessor<string | null>;
+	submitting: Accessor<boolean>;
+	/** Everything a `TextInput`/`PasswordInput` needs for one field */
+	field: (name: FieldName<S>) => {
+		get: () => string;
+		set: (value: string) => void;
+		validationSchema?: ZodTypeAny;
+		onValidationError: (message: string) => void;
+		error: string | null;
+	};
+	validate: () => boolean;
+	submit: () => Promise<void>;
+	reset: () => void;
+}
+
+function shapeOf(schema: FormSchema): Record<string, ZodTypeAny> {
+	return "shape" in schema ? schema.shape : schema.innerType().shape;
+}
+
+/**
+ * Field state, errors and submission for a form described by a zod schema.
+ * Fields validate on blur against their own part of the schema; `submit` validates the whole
+ * schema (refinements included) and only calls `onSubmit` when it passes.
+ *
+ * @param schema A `z.object` - or one with `.refine` - whose keys are the field names
+ * @param initial Starting values, one per field
+ * @param onSubmit Called with the parsed values - `submitting()` is true until it settles
+ */
+export function createForm<S extends FormSchema>(
+	options: CreateFormOptions<S>
+): FormApi<S> {
+	const [values, setValues] = createStore<Values<S>>({ ...options.initial });
+	const [errors, setErrors] = createStore<Errors<S>>({});
+	const [submitting, setSubmitting] = createSignal(false);
+	const shape = shapeOf(options.schema);
+
+	const setError = (name: FieldName<S>, message: string | undefined) =>
+		setErrors((current) => ({ ...current, [name]: message }));
+
+	const error = () =>
+		(Object.values(errors).find((message) => message) as string | undefined) ??
+		null;
+
+	const field = (name: FieldName<S>) => ({
+		get: () => (values[name] as string | undefined) ?? "",
+		set: (value: string) => {
+			setValues((current) => ({ ...current, [name]: value }));
+			setError(name, undefined);
+		},
+		validationSchema: shape[name],
+		onValidationError: (message: string) => setError(name, message),
+		get error() {
+			return errors[name] ?? null;
+		},
+	});
+
+	const validate = () => {
+		const result = options.schema.safeParse(values);
+		if (result.success) {
+			setErrors({});
+			return true;
+		}
+		const next: Errors<S> = {};
+		for (const issue of result.error.issues) {
+			const name = issue.path[0] as FieldName<S> | undefined;
+			if (name && !next[name]) next[name] = issue.message;
+		}
+		setErrors(next);
+		return false;
+	};
+
+	const submit = async () => {
+		if (submitting() || !validate()) return;
+		setSubmitting(true);
+		try {
+			await options.onSubmit(options.schema.parse(values));
+		} finally {
+			setSubmitting(false);
+		}
+	};
+
+	const reset = () => {
+		setValues({ ...options.initial });
+		setErrors({});
+	};
+
+	return {
+		values,
+		errors,
+		error,
+		submitting,
+		field,
+		validate,
+		submit,
+		reset,
+	};
+}
+
+interface FormProps<S extends FormSchema>
+	extends Omit<JSX.FormHTMLAttributes<HTMLFormElement>, "onSubmit"> {
+	form: FormApi<S>;
+}
+
+/**
+ * A `<form>` that hands submission to `createForm` - pressing enter or a `type="submit"`
+ * button validates, then calls `onSubmit`.
+ *
+ * @props form The `createForm` it submits
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Form<S extends FormSchema>(props: FormProps<S>) {
+	const [l, rest] = splitProps(props, ["form", "class", "children"]);
+	return (
+		<form
+			novalidate
+			class={cn(["flex flex-col gap-[12px]", l?.class])}
+			aria-busy={l.form.submitting() ? true : undefined}
+			onSubmit={(evt) => {
+				evt.preventDefault();
+				l.form.submit();
+			}}
+			{...rest}
+		>
+			{l.children}
+		</form>
+	);
+}
+
+interface FormFieldProps<S extends FormSchema> {
+	form: FormApi<S>;
+	name: FieldName<S>;
+	label: string;
+	type?: "text" | "email" | "password";
+	placeholder?: string;
+	autocomplete?: string;
+}
+
+/**
+ * One field of a `createForm`, rendered with the matching input atom.
+ *
+ * @props form The `createForm` the field belongs to
+ * @props name The schema key it edits
+ * @props type `password` renders a `PasswordInput`, anything else a `TextInput`
+ * @returns
+ */
+export function FormField<S extends FormSchema>(props: FormFieldProps<S>) {
+	const field = () => props.form.field(props.name);
+	return (
+		<Switch>
+			<Match when={props.type == "password"}>
+				<PasswordInput
+					name={props.name}
+					label={props.label}
+					placeholder={props.placeholder}
+					autocomplete={props.autocomplete}
+					get={field().get}
+					set={field().set}
+					validationSchema={field().validationSchema}
+					onValidationError={field().onValidationError}
+					error={field().error}
+				/>
+			</Match>
+			<Match when={props.type != "password"}>
+				<TextInput
+					name={props.name}
+					type={props.type ?? "text"}
+					label={props.label}
+					placeholder={props.placeholder}
+					autocomplete={props.autocomplete}
+					get={field().get}
+					set={field().set}
+					validationSchema={field().validationSchema}
+					onValidationError={field().onValidationError}
+					error={field().error}
+				/>
+			</Match>
+		</Switch>
+	);
+}
+
+export default Form;
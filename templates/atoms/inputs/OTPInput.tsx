@@ -0,0 +1,108 @@
+import { createSignal, createUniqueId, For, JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface OTPInputProps extends JSX.InputHTMLAttributes<HTMLInputElement> {
+	label: string;
+	length: number;
+	onComplete?: (value: string) => void;
+	type?: "single" | "bubble";
+}
+
+export function OTPInput(props: OTPInputProps) {
+	const [l, rest] = splitProps(props, [
+		"class",
+		"label",
+		"length",
+		"onComplete",
+		"type",
+	]);
+
+	let type = l?.type ?? "single";
+
+	const [values, setValues] = createSignal(Array(l.length).fill(""));
+
+	let inputs: HTMLInputElement[] = [];
+	const labelId = createUniqueId();
+
+	const handleInput = (index: number, event: Event) => {
+		const target = event.target as HTMLInputElement;
+		const newValue = target.value.slice(-1); // Only take the last character
+		const updatedValues = [...values()];
+		updatedValues[index] = newValue;
+		setValues(updatedValues);
+
+		// Move to the next input if available
+		if (newValue && index < l.length - 1) {
+			inputs[index + 1]?.focus();
+		}
+
+		// Call onComplete if all fields are filled
+		if (updatedValues.every((v) => v !== "") && l.onComplete) {
+			l.onComplete(updatedValues.join(""));
+		}
+	};
+
+	const handleKeyDown = (index: number, event: KeyboardEvent) => {
+		if (event.key === "Backspace" && !values()[index] && index > 0) {
+			inputs[index - 1]?.focus();
+		}
+	};
+
+	let containerCn = cn([
+		"flex justify-center items-center gap-[4px] text-2xl",
+		{
+			"bg-(--bg-a) hover:bg-(--bg-e) rounded-[6px] px-[6px] py-[6px] border-[2px] border-(--fg-e) hover:border-(--fg-i) has-[input:focus-within]:outline-[1px] has-[input:focus-within]:-outline-offset-[2px] has-[input:focus-within]:outline-(--c-a-e)":
+				type === "single",
+		},
+		{
+			"justify-between": type === "bubble",
+		},
+	]);
+	let wrapperCn = cn([
+		"select-none aspect-square flex justify-center items-center",
+		{
+			"bg-(--bg-a) hover:bg-(--bg-e) p-3 rounded-[6px] border-[2px] border-(--fg-e) hover:border-(--fg-i) has-[input:focus-within]:outline-[1px] has-[input:focus-within]:-outline-offset-[2px] has-[input:focus-within]:outline-(--c-a-e)":
+				type === "bubble",
+		},
+	]);
+	let inputCn = cn([
+		"select-none w-[2.5ch] aspect-square focus:outline-none text-center",
+		l?.class,
+	]);
+
+	return (
+		<div class="flex flex-col gap-[2px]">
+			<span
+				id={labelId}
+				class="text-[14px] select-none"
+			>
+				{l.label}
+			</span>
+			<div
+				role="group"
+				aria-labelledby={labelId}
+				class={containerCn}
+				onClick={() => inputs[0]?.focus()}
+			>
+				<For each={Array(l.length)}>
+					{(_, index) => (
+						<div class={wrapperCn}>
+							<input
+								ref={(el) => (inputs[index()] = el)}
+								type="text"
+								maxLength={1}
+								aria-label={`Digit ${index() + 1} of ${l.length}`}
+								value={values()[index()]}
+								class={inputCn}
+								onInput={(e) => handleInput(index(), e)}
+								onKeyDown={(e) => handleKeyDown(index(), e)}
+								onFocus={(e) => (e.target as HTMLInputElement).select()}
+								{...rest}
+							/>
+						</div>
+					)}
+				</For>
+			</div>
+		</div>
+	);
+}
@@ -0,0 +1,115 @@
+import { JSX, Match, splitProps, Switch, createSignal, createUniqueId } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+import IconButton from "~/devano/atoms/buttons/IconButton";
+import EyeOpen from "~/devano/atoms/icons/EyeOpen";
+import EyeClosed from "~/devano/atoms/icons/EyeClosed";
+import type { Accessor, Setter } from "solid-js";
+import { ZodType, ZodError } from "zod";
+
+interface ExtendedPasswordInputProps
+	extends JSX.InputHTMLAttributes<HTMLInputElement> {
+	label: string;
+	get: Accessor<string>;
+	set: Setter<string> | ((value: string) => void);
+	validationSchema?: ZodType<any>;
+	onValidationError?: (error: string) => void;
+	error?: string | null;
+}
+
+export function PasswordInput(props: ExtendedPasswordInputProps) {
+	const [vis, setVis] = createSignal(false);
+	const [l, most] = splitProps(props, [
+		"id",
+		"class",
+		"label",
+		"placeholder",
+		"get",
+		"set",
+		"validationSchema",
+		"onValidationError",
+		"error",
+	]);
+	const [unused, rest] = splitProps(most, [
+		"value",
+		"on:change",
+		"onChange",
+		"onchange",
+		"on:input",
+		"onInput",
+		"oninput",
+		"on:blur",
+		"onBlur",
+		"onblur",
+	]);
+
+	const [ownError, set_error] = createSignal<string | null>(null);
+	// an error handed in (e.g. from `createForm` on submit) wins over the last blur check
+	const error = () => l.error ?? ownError();
+	const id = l.id ?? createUniqueId();
+
+	let className = () =>
+		cn([
+			"select-none focus:outline-none focus:border-[transparent] flex-1",
+			l?.class,
+			error() ? "border-(--c-e-e) hover:border-(--c-e-i)" : "",
+		]);
+
+	const handleBlur = () => {
+		if (l.validationSchema) {
+			try {
+				l.validationSchema.parse(l.get());
+				set_error(null);
+			} catch (err) {
+				if (err instanceof ZodError) {
+					const errorMessage = err.errors[0]?.message || "Invalid Input";
+					set_error(errorMessage);
+					if (l.onValidationError) {
+						l.onValidationError(errorMessage);
+					}
+				}
+			}
+		}
+	};
+
+	return (
+		<div class="flex flex-col gap-[2px]">
+			<label
+				for={id}
+				class="text-[14px] select-none"
+			>
+				{l.label}
+			</label>
+			<div class="has-[input:focus-within]:outline-[1px] has-[input:focus-within]:-outline-offset-[2px] has-[input:focus-within]:outline-(--c-a-e) flex bg-(--bg-a) hover:bg-(--bg-e) rounded-[6px] px-[6px] py-[6px] border-[2px] border-(--fg-e) hover:border-(--fg-i)">
+				<input
+					type={vis() ? "text" : "password"}
+					id={id}
+					placeholder={l?.placeholder || l.label}
+					class={className()}
+					value={l.get()}
+					onInput={(evt) => l.set(evt.currentTarget.value)}
+					onBlur={handleBlur}
+					{...rest}
+				/>
+				<Switch fallback={<></>}>
+					<Match when={vis() == false}>
+						<IconButton
+							aria-label="Show password"
+							onClick={() => setVis(true)}
+						>
+							<EyeOpen size={24} />
+						</IconButton>
+					</Match>
+					<Match when={vis() == true}>
+						<IconButton
+							aria-label="Hide password"
+							onClick={() => setVis(false)}
+						>
+							<EyeClosed size={24} />
+						</IconButton>
+					</Match>
+				</Switch>
+			</div>
+			{error() && <span class="text-(--c-e-e) text-[12px]">{error()}</span>}
+		</div>
+	);
+}
@@ -0,0 +1,93 @@
+import { JSX, For, splitProps, createSignal, createUniqueId } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+import type { Accessor, Setter } from "solid-js";
+import { ZodType, ZodError } from "zod";
+
+export interface RadioOption {
+	value: string;
+	label: string;
+	disabled?: boolean;
+}
+
+interface RadioGroupProps extends JSX.FieldsetHTMLAttributes<HTMLFieldSetElement> {
+	label: string;
+	options: RadioOption[];
+	get: Accessor<string>;
+	set: Setter<string>;
+	name?: string;
+	validationSchema?: ZodType<any>;
+	onValidationError?: (error: string) => void;
+}
+
+/**
+ *
+ * @props options Required - the `{ value, label }` pairs, one radio each
+ * @props name Groups the radios for forms - generated when left out
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function RadioGroup(props: RadioGroupProps) {
+	const [l, rest] = splitProps(props, [
+		"class",
+		"label",
+		"options",
+		"name",
+		"get",
+		"set",
+		"validationSchema",
+		"onValidationError",
+	]);
+	const name = l.name ?? createUniqueId();
+
+	const [error, set_error] = createSignal<string | null>(null);
+
+	let className = () => cn(["flex flex-col gap-[6px]", l?.class]);
+
+	const validate = (value: string) => {
+		if (l.validationSchema) {
+			try {
+				l.validationSchema.parse(value);
+				set_error(null);
+			} catch (err) {
+				if (err instanceof ZodError) {
+					const errorMessage = err.errors[0]?.message || "Invalid Input";
+					set_error(errorMessage);
+					if (l.onValidationError) {
+						l.onValidationError(errorMessage);
+					}
+				}
+			}
+		}
+	};
+
+	return (
+		<fieldset
+			class={className()}
+			role="radiogroup"
+			aria-invalid={error() ? true : undefined}
+			{...rest}
+		>
+			<legend class="text-[14px] select-none mb-[2px]">{l.label}</legend>
+			<For each={l.options}>
+				{(option) => (
+					<label class="flex items-center gap-[6px] text-[14px] select-none">
+						<input
+							type="radio"
+							name={name}
+							value={option.value}
+							disabled={option.disabled}
+							checked={l.get() === option.value}
+							class="size-[18px] shrink-0 accent-(--c-a-e) focus-visible:outline-[1px] focus-visible:outline-offset-[2px] focus-visible:outline-(--c-a-e)"
+							onChange={(evt) => {
+								l.set(evt.currentTarget.value);
+								validate(evt.currentTarget.value);
+							}}
+						/>
+						{option.label}
+					</label>
+				)}
+			</For>
+			{error() && <span class="text-(--c-e-e) text-[12px]">{error()}</span>}
+		</fieldset>
+	);
+}
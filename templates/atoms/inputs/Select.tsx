@@ -0,0 +1,107 @@
+import { JSX, For, splitProps, createSignal } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+import type { Accessor, Setter } from "solid-js";
+import { ZodType, ZodError } from "zod";
+
+export interface SelectOption {
+	value: string;
+	label: string;
+	disabled?: boolean;
+}
+
+interface ExtendedSelectProps
+	extends JSX.SelectHTMLAttributes<HTMLSelectElement> {
+	label: string;
+	options: SelectOption[];
+	get: Accessor<string>;
+	set: Setter<string>;
+	placeholder?: string;
+	validationSchema?: ZodType<any>;
+	onValidationError?: (error: string) => void;
+}
+
+/**
+ *
+ * @props options Required - the `{ value, label }` pairs to choose from
+ * @props placeholder An empty, unselectable first option - shown until something is picked
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Select(props: ExtendedSelectProps) {
+	const [l, most] = splitProps(props, [
+		"class",
+		"label",
+		"options",
+		"placeholder",
+		"get",
+		"set",
+		"validationSchema",
+		"onValidationError",
+	]);
+	const [unused, rest] = splitProps(most, [
+		"value",
+		"on:change",
+		"onChange",
+		"onchange",
+		"on:blur",
+		"onBlur",
+		"onblur",
+	]);
+
+	const [error, set_error] = createSignal<string | null>(null);
+
+	let className = () =>
+		cn([
+			"select-none bg-(--bg-a) hover:bg-(--bg-e) rounded-[6px] px-[6px] py-[6px] border-[2px] border-(--fg-e) hover:border-(--fg-i) focus:outline-[1px] focus:-outline-offset-[2px] focus:outline-(--c-a-e)",
+			l?.class,
+			error() ? "border-(--c-e-e) hover:border-(--c-e-i)" : "",
+		]);
+
+	const validate = (value: string) => {
+		if (l.validationSchema) {
+			try {
+				l.validationSchema.parse(value);
+				set_error(null);
+			} catch (err) {
+				if (err instanceof ZodError) {
+					const errorMessage = err.errors[0]?.message || "Invalid Input";
+					set_error(errorMessage);
+					if (l.onValidationError) {
+						l.onValidationError(errorMessage);
+					}
+				}
+			}
+		}
+	};
+
+	return (
+		<label class="flex flex-col gap-[2px]">
+			<span class="text-[14px] select-none">{l.label}</span>
+			<select
+				class={className()}
+				value={l.get()}
+				aria-invalid={error() ? true : undefined}
+				onChange={(evt) => {
+					l.set(evt.currentTarget.value);
+					validate(evt.currentTarget.value);
+				}}
+				onBlur={() => validate(l.get())}
+				{...rest}
+			>
+				{l.placeholder && (
+					<option value="" disabled>
+						{l.placeholder}
+					</option>
+				)}
+				<For each={l.options}>
+					{(option) => (
+						<option value={option.value} disabled={option.disabled}>
+							{option.label}
+						</option>
+					)}
+				</For>
+			</select>
+			{error() && <span class="text-(--c-e-e) text-[12px]">{error()}</span>}
+		</label>
+	);
+}
@@ -0,0 +1,95 @@
+import { JSX, splitProps, createSignal } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+import type { Accessor, Setter } from "solid-js";
+import { ZodType, ZodError } from "zod";
+
+interface SwitchProps
+	extends JSX.ButtonHTMLAttributes<HTMLButtonElement> {
+	label: string;
+	get: Accessor<boolean>;
+	set: Setter<boolean>;
+	validationSchema?: ZodType<any>;
+	onValidationError?: (error: string) => void;
+}
+
+/**
+ * An on/off toggle - a `<button role="switch">`, so it's announced as a switch and flips
+ * with Space/Enter.
+ *
+ * @props get/set Required - the on/off signal
+ * @props class Extend/override the track's styles
+ * @returns
+ */
+export function Switch(props: SwitchProps) {
+	const [l, most] = splitProps(props, [
+		"class",
+		"label",
+		"get",
+		"set",
+		"validationSchema",
+		"onValidationError",
+	]);
+	const [unused, rest] = splitProps(most, [
+		"type",
+		"role",
+		"on:click",
+		"onClick",
+		"onclick",
+	]);
+
+	const [error, set_error] = createSignal<string | null>(null);
+
+	let className = () =>
+		cn([
+			"relative inline-flex h-[24px] w-[44px] shrink-0 items-center rounded-full border-[2px] border-(--fg-e) hover:border-(--fg-i) transition-colors focus-visible:outline-[1px] focus-visible:outline-offset-[2px] focus-visible:outline-(--c-a-e) disabled:opacity-50",
+			l.get() ? "bg-(--c-a-e)" : "bg-(--bg-e)",
+			l?.class,
+			error() ? "border-(--c-e-e) hover:border-(--c-e-i)" : "",
+		]);
+
+	const validate = (on: boolean) => {
+		if (l.validationSchema) {
+			try {
+				l.validationSchema.parse(on);
+				set_error(null);
+			} catch (err) {
+				if (err instanceof ZodError) {
+					const errorMessage = err.errors[0]?.message || "Invalid Input";
+					set_error(errorMessage);
+					if (l.onValidationError) {
+						l.onValidationError(errorMessage);
+					}
+				}
+			}
+		}
+	};
+
+	return (
+		<div class="flex flex-col gap-[2px]">
+			<label class="flex items-center gap-[6px] text-[14px] select-none">
+				<button
+					type="button"
+					role="switch"
+					aria-checked={l.get()}
+					aria-invalid={error() ? true : undefined}
+					class={className()}
+					onClick={() => {
+						const on = !l.get();
+						l.set(on);
+						validate(on);
+					}}
+					{...rest}
+				>
+					<span
+						class={cn([
+							"inline-block size-[16px] rounded-full bg-(--fg-i) transition-transform",
+							l.get() ? "translate-x-[22px]" : "translate-x-[2px]",
+						])}
+					/>
+				</button>
+				{l.label}
+			</label>
+			{error() && <span class="text-(--c-e-e) text-[12px]">{error()}</span>}
+		</div>
+	);
+}
@@ -0,0 +1,79 @@
+import { JSX, splitProps, createSignal } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+import type { Accessor, Setter } from "solid-js";
+import { ZodType, ZodError } from "zod";
+
+interface ExtendedTextareaProps
+	extends JSX.TextareaHTMLAttributes<HTMLTextAreaElement> {
+	label: string;
+	get: Accessor<string>;
+	set: Setter<string>;
+	validationSchema?: ZodType<any>;
+	onValidationError?: (error: string) => void;
+}
+
+export function Textarea(props: ExtendedTextareaProps) {
+	const [l, most] = splitProps(props, [
+		"class",
+		"label",
+		"placeholder",
+		"get",
+		"set",
+		"validationSchema",
+		"onValidationError",
+	]);
+	const [unused, rest] = splitProps(most, [
+		"value",
+		"on:change",
+		"onChange",
+		"onchange",
+		"on:input",
+		"onInput",
+		"oninput",
+		"on:blur",
+		"onBlur",
+		"onblur",
+	]);
+
+	const [error, set_error] = createSignal<string | null>(null);
+
+	let className = () =>
+		cn([
+			"min-h-[96px] resize-y bg-(--bg-a) hover:bg-(--bg-e) rounded-[6px] px-[6px] py-[6px] border-[2px] border-(--fg-e) hover:border-(--fg-i) focus:outline-[1px] focus:-outline-offset-[2px] focus:outline-(--c-a-e)",
+			l?.class,
+			error() ? "border-(--c-e-e) hover:border-(--c-e-i)" : "",
+		]);
+
+	const handleBlur = () => {
+		if (l.validationSchema) {
+			try {
+				l.validationSchema.parse(l.get());
+				set_error(null);
+			} catch (err) {
+				if (err instanceof ZodError) {
+					const errorMessage = err.errors[0]?.message || "Invalid Input";
+					set_error(errorMessage);
+					if (l.onValidationError) {
+						l.onValidationError(errorMessage);
+					}
+				}
+			}
+		}
+	};
+
+	return (
+		<label class="flex flex-col gap-[2px]">
+			<span class="text-[14px] select-none">{l.label}</span>
+			<textarea
+				placeholder={l?.placeholder || l.label}
+				class={className()}
+				value={l.get()}
+				aria-invalid={error() ? true : undefined}
+				onInput={(evt) => l.set(evt.currentTarget.value)}
+				onBlur={handleBlur}
+				{...rest}
+			/>
+			{error() && <span class="text-(--c-e-e) text-[12px]">{error()}</span>}
+		</label>
+	);
+}
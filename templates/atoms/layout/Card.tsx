@@ -0,0 +1,22 @@
+import { JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface CardProps extends JSX.HTMLAttributes<HTMLDivElement> {}
+
+export function Card(props: CardProps) {
+	let [l, rest] = splitProps(props, ["class", "children"]);
+
+	let cardCN = cn([
+		"flex flex-col w-full sm:w-[35ch] border-[2px] border-(--fg-e) px-[16px] sm:px-[24px] py-[12px] pb-[24px] {{devano:radius}} gap-[16px] sm:gap-[24px]",
+		l?.class,
+	]);
+
+	return (
+		<div
+			class={cardCN}
+			{...rest}
+		>
+			{l.children}
+		</div>
+	);
+}
@@ -0,0 +1,51 @@
+import { JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface EmptyStateProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	title: string;
+	description?: string;
+	icon?: JSX.Element;
+	action?: JSX.Element;
+}
+
+/**
+ * What to show where a list, table or search has nothing in it yet.
+ *
+ * @props title Required - what's empty, e.g. "No projects yet"
+ * @props description What to do about it
+ * @props icon Decorative - shown above the title
+ * @props action A Button or link that fixes it, e.g. "Create a project"
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function EmptyState(props: EmptyStateProps) {
+	const [l, rest] = splitProps(props, [
+		"title",
+		"description",
+		"icon",
+		"action",
+		"class",
+	]);
+	const emptyCn = cn([
+		"flex flex-col items-center justify-center gap-[12px] px-[16px] py-[32px] sm:px-[24px] sm:py-[48px] text-center rounded-[12px] border-[2px] border-dashed border-(--fg-a) text-(--fg-e)",
+		l?.class,
+	]);
+	return (
+		<div
+			class={emptyCn}
+			{...rest}
+		>
+			{l.icon && (
+				<div
+					class="text-(--fg-a) [&>svg]:size-[40px]"
+					aria-hidden="true"
+				>
+					{l.icon}
+				</div>
+			)}
+			<h3 class="text-[18px] font-semibold">{l.title}</h3>
+			{l.description && <p class="max-w-[45ch] text-[14px]">{l.description}</p>}
+			{l.action}
+		</div>
+	);
+}
@@ -0,0 +1,75 @@
+import { JSX, splitProps, Switch, Match } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface HeadingProps extends JSX.HTMLAttributes<HTMLHeadingElement> {
+	as: "h1" | "h2" | "h3" | "h4" | "h5" | "h6";
+}
+
+export function Heading(props: HeadingProps) {
+	const [local, rest] = splitProps(props, ["as", "children", "class"]);
+
+	let headingCn = cn([
+		{
+			h1: "text-3xl sm:text-4xl font-bold",
+			h2: "text-2xl sm:text-3xl font-semibold",
+			h3: "text-xl sm:text-2xl font-medium",
+			h4: "text-xl font-medium",
+			h5: "text-lg font-normal",
+			h6: "text-base font-normal",
+		}[local.as],
+		local.class,
+	]);
+
+	return (
+		<Switch>
+			<Match when={local.as === "h1"}>
+				<h1
+					class={headingCn}
+					{...rest}
+				>
+					{local.children}
+				</h1>
+			</Match>
+			<Match when={local.as === "h2"}>
+				<h2
+					class={headingCn}
+					{...rest}
+				>
+					{local.children}
+				</h2>
+			</Match>
+			<Match when={local.as === "h3"}>
+				<h3
+					class={headingCn}
+					{...rest}
+				>
+					{local.children}
+				</h3>
+			</Match>
+			<Match when={local.as === "h4"}>
+				<h4
+					class={headingCn}
+					{...rest}
+				>
+					{local.children}
+				</h4>
+			</Match>
+			<Match when={local.as === "h5"}>
+				<h5
+					class={headingCn}
+					{...rest}
+				>
+					{local.children}
+				</h5>
+			</Match>
+			<Match when={local.as === "h6"}>
+				<h6
+					class={headingCn}
+					{...rest}
+				>
+					{local.children}
+				</h6>
+			</Match>
+		</Switch>
+	);
+}
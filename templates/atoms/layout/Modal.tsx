@@ -0,0 +1,66 @@
+import { Portal, Show, Switch, Match } from "solid-js/web";
+import {
+	JSX,
+	splitProps,
+	createEffect,
+	createSignal,
+	createUniqueId,
+} from "solid-js";
+import { createDismiss, createFocusTrap } from "~/devano/utils/overlay";
+import { Card } from "./Card";
+
+interface ModalProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	when: boolean;
+	close: () => void;
+	label?: string;
+}
+
+/**
+ *
+ * @props when Required - whether the modal is open
+ * @props close Required - called on Escape or a click outside the modal
+ * @props label The accessible name of the dialog - leave it out to label the dialog with its first heading
+ * @returns
+ */
+export default function Modal(props: ModalProps) {
+	const [l, rest] = splitProps(props, ["when", "children", "close", "label"]);
+	const [panel, setPanel] = createSignal<HTMLDivElement>();
+	const [labelledBy, setLabelledBy] = createSignal<string>();
+	const headingId = createUniqueId();
+	const open = () => l.when;
+
+	createDismiss({ open, onDismiss: () => l.close(), inside: () => [panel()] });
+	createFocusTrap(panel, open);
+
+	// no `label` - name the dialog after its first heading instead
+	createEffect(() => {
+		const element = panel();
+		if (l.label || !element) return;
+		const heading = element.querySelector<HTMLElement>("h1, h2, h3, h4, h5, h6");
+		if (!heading) return;
+		heading.id ||= headingId;
+		setLabelledBy(heading.id);
+	});
+
+	return (
+		<Switch fallback={<></>}>
+			<Match when={l.when}>
+				<Portal>
+					<div class="fixed inset-0 bg-(--bg-a)/25 animate-fade-in" />
+					<Card
+						ref={setPanel}
+						role="dialog"
+						aria-modal="true"
+						aria-label={l.label}
+						aria-labelledby={l.label ? undefined : labelledBy()}
+						tabindex="-1"
+						class="fixed left-[50%] top-[50%] w-[calc(100%-32px)] sm:w-[35ch] max-h-[calc(100dvh-32px)] overflow-y-auto bg-(--bg-e) text-(--fg-e) animate-scale-in"
+						style={{ transform: "translateX(-50%) translateY(-50%)" }}
+					>
+						{l?.children}
+					</Card>
+				</Portal>
+			</Match>
+		</Switch>
+	);
+}
@@ -0,0 +1,11 @@
+import { JSX, splitProps } from "solid-js";
+
+export function Page(props: JSX.HTMLAttributes<HTMLElement>) {
+	const [l, rest] = splitProps(props, ["class"]);
+	return (
+		<main
+			class="flex flex-col w-full min-h-[100dvh] px-[16px] sm:px-[24px] gap-[24px] md:gap-[48px] items-center bg-(--bg-i) text-(--fg-e)"
+			{...rest}
+		/>
+	);
+}
@@ -0,0 +1,34 @@
+import { JSX, splitProps } from "solid-js";
+import { cn } from "~/devano/utils/cn";
+
+interface PageInnerProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	header?: JSX.Element;
+	footer?: JSX.Element;
+}
+
+/**
+ *
+ * @props header Page chrome above the content, e.g. a `Navbar`
+ * @props footer Page chrome below the content, e.g. a `Footer`
+ * @props class Extend/override default styles
+ * @returns
+ */
+export default function PageInner(props: PageInnerProps) {
+	const [l, rest] = splitProps(props, ["class", "header", "footer", "children"]);
+
+	const innerCn = cn([
+		"flex flex-col h-[100dvh] w-full items-center justify-between",
+		l?.class,
+	]);
+
+	return (
+		<div
+			class={innerCn}
+			{...rest}
+		>
+			{l.header && <div class="flex-none w-full">{l.header}</div>}
+			<div class="flex-1 w-full overflow-y-scroll pb-[24px]">{l.children}</div>
+			{l.footer && <div class="flex-none w-full">{l.footer}</div>}
+		</div>
+	);
+}
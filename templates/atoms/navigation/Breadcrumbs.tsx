@@ -0,0 +1,70 @@
+import { JSX, For, splitProps } from "solid-js";
+import { A } from "@solidjs/router";
+import { cn } from "~/devano/utils/cn";
+
+export interface Crumb {
+	label: string;
+	href?: string;
+}
+
+interface BreadcrumbsProps extends JSX.HTMLAttributes<HTMLElement> {
+	items: Crumb[];
+}
+
+/**
+ * Where the current page sits in the site. The last item is the current page. Below `sm`
+ * only the parent and current page are shown, so the trail never wraps on a phone.
+ *
+ * @props items Required - `{ label, href? }` from the root down to the current page
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Breadcrumbs(props: BreadcrumbsProps) {
+	const [l, rest] = splitProps(props, ["items", "class"]);
+	const last = () => l.items.length - 1;
+
+	return (
+		<nav
+			aria-label="Breadcrumb"
+			class={cn(["text-[14px] text-(--fg-e)", l?.class])}
+			{...rest}
+		>
+			<ol class="flex items-center gap-[6px] min-w-0">
+				<For each={l.items}>
+					{(item, index) => (
+						<li
+							class={cn([
+								"flex items-center gap-[6px] min-w-0",
+								index() < last() - 1 ? "hidden sm:flex" : "",
+							])}
+						>
+							{index() === last() || !item.href ? (
+								<span
+									class="truncate font-semibold"
+									aria-current={index() === last() ? "page" : undefined}
+								>
+									{item.label}
+								</span>
+							) : (
+								<A
+									href={item.href}
+									class="truncate hover:text-(--fg-i) hover:underline focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)"
+								>
+									{item.label}
+								</A>
+							)}
+							{index() < last() && (
+								<span
+									aria-hidden="true"
+									class="text-(--fg-a)"
+								>
+									/
+								</span>
+							)}
+						</li>
+					)}
+				</For>
+			</ol>
+		</nav>
+	);
+}
@@ -0,0 +1,68 @@
+import { JSX, For, splitProps } from "solid-js";
+import { A } from "@solidjs/router";
+import { cn } from "~/devano/utils/cn";
+export interface FooterLink {
+	href: string;
+	label: string;
+}
+
+export interface FooterColumn {
+	title: string;
+	links: FooterLink[];
+}
+
+interface FooterProps extends JSX.HTMLAttributes<HTMLElement> {
+	columns?: FooterColumn[];
+	legal?: JSX.Element;
+}
+
+/**
+ * The bottom of every page. Link columns stack on phones, sit side by side from `sm` and
+ * share the row with `children` from `md`.
+ *
+ * @props columns Titled groups of links
+ * @props legal The small print under everything else, e.g. "© 2025 Acme"
+ * @props children Anything else - a newsletter signup, your logo, social links
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Footer(props: FooterProps) {
+	const [l, rest] = splitProps(props, ["columns", "legal", "class", "children"]);
+	const footerCn = cn([
+		"flex flex-col w-full gap-[24px] px-[12px] py-[24px] md:px-[24px] bg-(--bg-e) text-(--fg-e) border-t-[1px] border-(--fg-a)",
+		l?.class,
+	]);
+	return (
+		<footer
+			class={footerCn}
+			{...rest}
+		>
+			<div class="flex flex-col md:flex-row gap-[24px]">
+				{l.children && <div class="md:flex-1">{l.children}</div>}
+				<div class="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-4 gap-[24px] md:flex-[2]">
+					<For each={l.columns ?? []}>
+						{(column) => (
+							<nav
+								aria-label={column.title}
+								class="flex flex-col gap-[6px]"
+							>
+								<span class="font-semibold">{column.title}</span>
+								<For each={column.links}>
+									{(link) => (
+										<A
+											href={link.href}
+											class="text-[14px] hover:text-(--fg-i) hover:underline focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)"
+										>
+											{link.label}
+										</A>
+									)}
+								</For>
+							</nav>
+						)}
+					</For>
+				</div>
+			</div>
+			{l.legal && <div class="text-[12px] text-(--fg-a)">{l.legal}</div>}
+		</footer>
+	);
+}
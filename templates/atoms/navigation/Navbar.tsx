@@ -0,0 +1,124 @@
+import { JSX, For, splitProps, createEffect, createSignal, on } from "solid-js";
+import { A, useLocation } from "@solidjs/router";
+import { cn } from "~/devano/utils/cn";
+import IconButton from "~/devano/atoms/buttons/IconButton";
+import { Drawer } from "~/devano/atoms/overlays/Drawer";
+
+export interface NavLink {
+	href: string;
+	label: string;
+	/** Only highlight on an exact match - use for `/`. */
+	end?: boolean;
+}
+
+interface NavbarProps extends JSX.HTMLAttributes<HTMLElement> {
+	links: NavLink[];
+	brand?: JSX.Element;
+	actions?: JSX.Element;
+	label?: string;
+}
+
+const linkCn =
+	"px-[8px] py-[4px] rounded-[6px] text-(--fg-e) hover:text-(--fg-i) hover:bg-(--bg-e) focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)";
+const activeCn = "text-(--c-a-e) font-semibold";
+
+/**
+ * A top bar with the site's links. From `md` up the links sit inline; below it they move
+ * into a Drawer behind a menu button, which closes itself on navigation.
+ *
+ * @props links Required - `{ href, label, end? }` for each link; the current route is highlighted
+ * @props brand Your logo or site name, linked or not - shown first
+ * @props actions Buttons for the right-hand side, e.g. log in - shown on every breakpoint
+ * @props label The navigation landmark's name - defaults to "Main"
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Navbar(props: NavbarProps) {
+	const [l, rest] = splitProps(props, [
+		"links",
+		"brand",
+		"actions",
+		"label",
+		"class",
+	]);
+	const [menuOpen, setMenuOpen] = createSignal(false);
+	const location = useLocation();
+	createEffect(on(() => location.pathname, () => setMenuOpen(false)));
+
+	const navbarCn = cn([
+		"sticky top-0 z-30 flex w-full items-center gap-[12px] px-[12px] py-[8px] md:px-[24px] bg-(--bg-i) text-(--fg-e) border-b-[1px] border-(--fg-a)",
+		l?.class,
+	]);
+
+	return (
+		<header
+			class={navbarCn}
+			{...rest}
+		>
+			{l.brand && <div class="flex items-center shrink-0">{l.brand}</div>}
+			<nav
+				aria-label={l.label ?? "Main"}
+				class="hidden md:flex items-center gap-[4px]"
+			>
+				<For each={l.links}>
+					{(link) => (
+						<A
+							href={link.href}
+							end={link.end}
+							class={linkCn}
+							activeClass={activeCn}
+						>
+							{link.label}
+						</A>
+					)}
+				</For>
+			</nav>
+			<div class="ml-auto flex items-center gap-[6px]">
+				{l.actions}
+				<IconButton
+					class="md:hidden"
+					outline
+					aria-label="Open menu"
+					aria-expanded={menuOpen()}
+					onClick={() => setMenuOpen(true)}
+				>
+					<svg
+						viewBox="0 0 24 24"
+						class="size-[20px]"
+						fill="none"
+						stroke="currentColor"
+						stroke-width="2"
+						stroke-linecap="round"
+						aria-hidden="true"
+					>
+						<path d="M4 6h16M4 12h16M4 18h16" />
+					</svg>
+				</IconButton>
+			</div>
+			<Drawer
+				when={menuOpen()}
+				close={() => setMenuOpen(false)}
+				label="Menu"
+				side="right"
+			>
+				<nav
+					aria-label={l.label ?? "Main"}
+					class="flex flex-col gap-[4px]"
+				>
+					<For each={l.links}>
+						{(link) => (
+							<A
+								href={link.href}
+								end={link.end}
+								class={cn([linkCn, "py-[8px]"])}
+								activeClass={activeCn}
+							>
+								{link.label}
+							</A>
+						)}
+					</For>
+				</nav>
+			</Drawer>
+		</header>
+	);
+}
@@ -0,0 +1,88 @@
+import { JSX, For, Show, splitProps, createEffect, createSignal, createUniqueId, on } from "solid-js";
+import { A, useLocation } from "@solidjs/router";
+import { cn } from "~/devano/utils/cn";
+export interface SidebarLink {
+	href: string;
+	label: string;
+	/** Only highlight on an exact match - use for `/`. */
+	end?: boolean;
+}
+
+export interface SidebarSection {
+	title?: string;
+	links: SidebarLink[];
+}
+
+interface SidebarProps extends JSX.HTMLAttributes<HTMLElement> {
+	sections: SidebarSection[];
+	label: string;
+}
+
+/**
+ * Section navigation for docs and dashboards. From `md` up it's a sticky column; below it
+ * collapses into a full-width "label" toggle above the content, closing on navigation.
+ *
+ * @props sections Required - groups of links, each with an optional title
+ * @props label Required - the navigation landmark's name, also the mobile toggle's text
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Sidebar(props: SidebarProps) {
+	const [l, rest] = splitProps(props, ["sections", "label", "class"]);
+	const id = createUniqueId();
+	const [open, setOpen] = createSignal(false);
+	const location = useLocation();
+	createEffect(on(() => location.pathname, () => setOpen(false)));
+
+	const sidebarCn = cn([
+		"flex flex-col w-full md:w-[240px] md:shrink-0 md:sticky md:top-0 md:max-h-[100dvh] md:overflow-y-auto gap-[6px] p-[12px] text-(--fg-e)",
+		l?.class,
+	]);
+
+	return (
+		<nav
+			aria-label={l.label}
+			class={sidebarCn}
+			{...rest}
+		>
+			<button
+				type="button"
+				class="md:hidden flex items-center justify-between px-[8px] py-[6px] rounded-[6px] border-[2px] border-(--fg-a) font-semibold hover:cursor-pointer focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)"
+				aria-expanded={open()}
+				aria-controls={id}
+				onClick={() => setOpen(!open())}
+			>
+				{l.label}
+				<span aria-hidden="true">{open() ? "−" : "+"}</span>
+			</button>
+			<div
+				id={id}
+				class={cn(["flex-col gap-[12px] md:flex", open() ? "flex" : "hidden"])}
+			>
+				<For each={l.sections}>
+					{(section) => (
+						<div class="flex flex-col gap-[2px]">
+							<Show when={section.title}>
+								<span class="px-[8px] pb-[2px] text-[12px] font-semibold uppercase tracking-wide text-(--fg-a)">
+									{section.title}
+								</span>
+							</Show>
+							<For each={section.links}>
+								{(link) => (
+									<A
+										href={link.href}
+										end={link.end}
+										class="px-[8px] py-[6px] rounded-[6px] hover:bg-(--bg-e) hover:text-(--fg-i) focus-visible:outline-[1px] focus-visible:outline-(--c-a-e)"
+										activeClass="bg-(--c-a-a) text-(--c-a-i) font-semibold"
+									>
+										{link.label}
+									</A>
+								)}
+							</For>
+						</div>
+					)}
+				</For>
+			</div>
+		</nav>
+	);
+}
@@ -0,0 +1,71 @@
+import { JSX, Show, splitProps, createSignal } from "solid-js";
+import { Portal } from "solid-js/web";
+import { cn } from "~/devano/utils/cn";
+import { createDismiss, createFocusTrap } from "~/devano/utils/overlay";
+
+type Side = "left" | "right" | "top" | "bottom";
+
+interface DrawerProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	when: boolean;
+	close: () => void;
+	label: string;
+	side?: Side;
+}
+
+const sides: Record<Side, string> = {
+	left: "left-0 top-0 h-[100dvh] w-[min(320px,85vw)] animate-fade-in",
+	right: "right-0 top-0 h-[100dvh] w-[min(320px,85vw)] animate-fade-in",
+	top: "left-0 top-0 w-[100vw] max-h-[85dvh] animate-slide-down",
+	bottom: "left-0 bottom-0 w-[100vw] max-h-[85dvh] animate-slide-up",
+};
+
+/**
+ *
+ * @props when Required - whether the drawer is open
+ * @props close Required - called on Escape, a backdrop click, or anything else that should close it
+ * @props label Required - the accessible name of the drawer
+ * @props side Which edge it slides in from - defaults to `right`
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Drawer(props: DrawerProps) {
+	const [l, rest] = splitProps(props, [
+		"when",
+		"close",
+		"label",
+		"side",
+		"class",
+		"children",
+	]);
+	const [panel, setPanel] = createSignal<HTMLDivElement>();
+	const open = () => l.when;
+
+	createDismiss({ open, onDismiss: () => l.close(), inside: () => [panel()] });
+	createFocusTrap(panel, open);
+
+	const drawerCn = () =>
+		cn([
+			"fixed z-50 flex flex-col gap-[12px] p-[18px] overflow-y-auto bg-(--bg-e) text-(--fg-e)",
+			sides[l.side ?? "right"],
+			l?.class,
+		]);
+
+	return (
+		<Show when={l.when}>
+			<Portal>
+				<div class="fixed inset-0 z-40 bg-(--bg-a)/25 animate-fade-in" />
+				<div
+					ref={setPanel}
+					role="dialog"
+					aria-modal="true"
+					aria-label={l.label}
+					tabindex="-1"
+					class={drawerCn()}
+					{...rest}
+				>
+					{l.children}
+				</div>
+			</Portal>
+		</Show>
+	);
+}
@@ -0,0 +1,144 @@
+import { JSX, For, Show, splitProps, createSignal, createUniqueId } from "solid-js";
+import { Portal } from "solid-js/web";
+import { cn } from "~/devano/utils/cn";
+import { createDismiss, createPosition, focusableIn } from "~/devano/utils/overlay";
+import type { Align, Placement, TriggerProps } from "~/devano/utils/overlay";
+
+export interface MenuItem {
+	label: string;
+	onSelect: () => void;
+	disabled?: boolean;
+}
+
+interface DropdownMenuProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	trigger: (props: TriggerProps) => JSX.Element;
+	label: string;
+	items: MenuItem[];
+	placement?: Placement;
+	align?: Align;
+}
+
+/**
+ * A menu of actions. Arrow keys, Home and End move between items, Escape closes and
+ * returns focus to the trigger, Tab closes and moves on.
+ *
+ * @props trigger Required - renders the element that opens the menu; spread the props it's given onto it
+ * @props label Required - the accessible name of the menu
+ * @props items Required - `{ label, onSelect }` for each action
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function DropdownMenu(props: DropdownMenuProps) {
+	const [l, rest] = splitProps(props, [
+		"trigger",
+		"label",
+		"items",
+		"placement",
+		"align",
+		"class",
+	]);
+	const id = createUniqueId();
+	const [open, setOpen] = createSignal(false);
+	const [anchor, setAnchor] = createSignal<HTMLElement>();
+	const [menu, setMenu] = createSignal<HTMLDivElement>();
+	const position = createPosition(
+		anchor,
+		menu,
+		open,
+		l.placement ?? "bottom",
+		l.align ?? "start",
+	);
+
+	const close = (returnFocus: boolean) => {
+		setOpen(false);
+		if (returnFocus) anchor()?.focus();
+	};
+	createDismiss({
+		open,
+		onDismiss: () => close(true),
+		inside: () => [anchor(), menu()],
+	});
+
+	const focusItem = (index: (current: number, count: number) => number) => {
+		const element = menu();
+		if (!element) return;
+		const items = focusableIn(element);
+		const current = items.indexOf(document.activeElement as HTMLElement);
+		items[index(current, items.length)]?.focus();
+	};
+	const onKeyDown = (evt: KeyboardEvent) => {
+		switch (evt.key) {
+			case "ArrowDown":
+				evt.preventDefault();
+				focusItem((current, count) => (current + 1) % count);
+				break;
+			case "ArrowUp":
+				evt.preventDefault();
+				focusItem((current, count) => (current - 1 + count) % count);
+				break;
+			case "Home":
+				evt.preventDefault();
+				focusItem(() => 0);
+				break;
+			case "End":
+				evt.preventDefault();
+				focusItem((_, count) => count - 1);
+				break;
+			case "Tab":
+				close(false);
+				break;
+		}
+	};
+
+	const menuCn = cn([
+		"fixed z-50 flex flex-col min-w-[160px] py-[4px] rounded-[8px] bg-(--bg-e) text-(--fg-e) border-[1px] border-(--fg-a) animate-scale-in",
+		l?.class,
+	]);
+
+	return (
+		<>
+			{l.trigger({
+				ref: setAnchor,
+				onClick: () => {
+					setOpen(!open());
+					if (open()) queueMicrotask(() => focusItem(() => 0));
+				},
+				"aria-expanded": open(),
+				"aria-controls": id,
+				"aria-haspopup": "menu",
+			})}
+			<Show when={open()}>
+				<Portal>
+					<div
+						ref={setMenu}
+						id={id}
+						role="menu"
+						aria-label={l.label}
+						class={menuCn}
+						style={{ top: `${position().top}px`, left: `${position().left}px` }}
+						onKeyDown={onKeyDown}
+						{...rest}
+					>
+						<For each={l.items}>
+							{(item) => (
+								<button
+									type="button"
+									role="menuitem"
+									tabindex="-1"
+									disabled={item.disabled}
+									class="text-left px-[12px] py-[6px] hover:bg-(--bg-i) focus-visible:bg-(--bg-i) focus-visible:outline-none disabled:opacity-50"
+									onClick={() => {
+										item.onSelect();
+										close(true);
+									}}
+								>
+									{item.label}
+								</button>
+							)}
+						</For>
+					</div>
+				</Portal>
+			</Show>
+		</>
+	);
+}
@@ -0,0 +1,86 @@
+import { JSX, Show, splitProps, createSignal, createUniqueId } from "solid-js";
+import { Portal } from "solid-js/web";
+import { cn } from "~/devano/utils/cn";
+import {
+	createDismiss,
+	createFocusTrap,
+	createPosition,
+} from "~/devano/utils/overlay";
+import type { Align, Placement, TriggerProps } from "~/devano/utils/overlay";
+
+interface PopoverProps extends JSX.HTMLAttributes<HTMLDivElement> {
+	trigger: (props: TriggerProps) => JSX.Element;
+	label: string;
+	placement?: Placement;
+	align?: Align;
+}
+
+/**
+ *
+ * @props trigger Required - renders the element that opens the popover; spread the props it's given onto it
+ * @props label Required - the accessible name of the popover panel
+ * @props placement Which side of the trigger to open on - flips when there's no room
+ * @props class Extend/override default styles
+ * @returns
+ */
+export function Popover(props: PopoverProps) {
+	const [l, rest] = splitProps(props, [
+		"trigger",
+		"label",
+		"placement",
+		"align",
+		"class",
+		"children",
+	]);
+	const id = createUniqueId();
+	const [open, setOpen] = createSignal(false);
+	const [anchor, setAnchor] = createSignal<HTMLElement>();
+	const [panel, setPanel] = createSignal<HTMLDivElement>();
+	const position = createPosition(
+		anchor,
+		panel,
+		open,
+		l.placement ?? "bottom",
+		l.align ?? "center",
+	);
+
+	createDismiss({
+		open,
+		onDismiss: () => setOpen(false),
+		inside: () => [anchor(), panel()],
+	});
+	createFocusTrap(panel, open);
+
+	const popoverCn = cn([
+		"fixed z-50 flex flex-col gap-[6px] p-[12px] rounded-[12px] bg-(--bg-e) text-(--fg-e) border-[1px] border-(--fg-a) animate-scale-in",
+		l?.class,
+	]);
+
+	return (
+		<>
+			{l.trigger({
+				ref: setAnchor,
+				onClick: () => setOpen(!open()),
+				"aria-expanded": open(),
+				"aria-controls": id,
+				"aria-haspopup": "dialog",
+			})}
+			<Show when={open()}>
+				<Portal>
+					<div
+						ref={setPanel}
+						id={id}
+						role="dialog"
+						aria-label={l.label}
+						tabindex="-1"
+						class={popoverCn}
+						style={{ top: `${position().top}px`, left: `${position().left}px` }}
+						{...rest}
+					>
+						{l.children}
+					</div>
+				</Portal>
+			</Show>
+		</>
+	);
+}
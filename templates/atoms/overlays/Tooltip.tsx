@@ -0,0 +1,84 @@
+import { JSX, Show, splitProps, createSignal, createUniqueId, onCleanup } from "solid-js";
+import { Portal } from "solid-js/web";
+import { cn } from "~/devano/utils/cn";
+import { createDismiss, createPosition } from "~/devano/utils/overlay";
+import type { Placement } from "~/devano/utils/overlay";
+
+interface TooltipProps extends JSX.HTMLAttributes<HTMLSpanElement> {
+	content: JSX.Element;
+	placement?: Placement;
+	delay?: number;
+}
+
+/**
+ * Shows `content` while its children are hovered or focused. The tooltip describes the
+ * children (`aria-describedby`), so keep it short and non-interactive - use a Popover for
+ * anything clickable.
+ *
+ * @props content Required - what the tooltip says
+ * @props placement Which side to show on - defaults to `top`, flips when there's no room
+ * @props delay Milliseconds of hover before showing - defaults to 300
+ * @returns
+ */
+export function Tooltip(props: TooltipProps) {
+	const [l, rest] = splitProps(props, [
+		"content",
+		"placement",
+		"delay",
+		"class",
+		"children",
+	]);
+	const id = createUniqueId();
+	const [open, setOpen] = createSignal(false);
+	const [anchor, setAnchor] = createSignal<HTMLSpanElement>();
+	const [tip, setTip] = createSignal<HTMLDivElement>();
+	const position = createPosition(anchor, tip, open, l.placement ?? "top");
+
+	let timer: ReturnType<typeof setTimeout> | undefined;
+	const show = (delay: number) => {
+		clearTimeout(timer);
+		timer = setTimeout(() => setOpen(true), delay);
+	};
+	const hide = () => {
+		clearTimeout(timer);
+		setOpen(false);
+	};
+	onCleanup(() => clearTimeout(timer));
+
+	createDismiss({ open, onDismiss: hide, inside: () => [], clickOutside: false });
+
+	const tooltipCn = cn([
+		"fixed z-50 max-w-[240px] px-[8px] py-[4px] rounded-[6px] text-[12px] bg-(--fg-e) text-(--bg-a) pointer-events-none animate-fade-in",
+		l?.class,
+	]);
+
+	return (
+		<>
+			<span
+				ref={setAnchor}
+				class="inline-flex"
+				aria-describedby={open() ? id : undefined}
+				onMouseEnter={() => show(l.delay ?? 300)}
+				onMouseLeave={hide}
+				onFocusIn={() => show(0)}
+				onFocusOut={hide}
+				{...rest}
+			>
+				{l.children}
+			</span>
+			<Show when={open()}>
+				<Portal>
+					<div
+						ref={setTip}
+						id={id}
+						role="tooltip"
+						class={tooltipCn}
+						style={{ top: `${position().top}px`, left: `${position().left}px` }}
+					>
+						{l.content}
+					</div>
+				</Portal>
+			</Show>
+		</>
+	);
+}
@@ -0,0 +1,15 @@
+import { AuthProvider } from "~/devano/features/auth/AuthState";
+import AuthInner from "~/devano/features/auth/AuthInner";
+import { ToastProvider } from "~/devano/atoms/feedback/Toast";
+
+export default function Auth() {
+	return (
+		<div>
+			<ToastProvider>
+				<AuthProvider>
+					<AuthInner />
+				</AuthProvider>
+			</ToastProvider>
+		</div>
+	);
+}
@@ -1,6 +1,3 @@
-use crate::library::client::utils::utils::UiComponent;
-
-pub const AUTH_INNER_LIT: &str = r#"
 import { LogInForm } from "~/devano/features/auth/LogInForm";
 import { RegisterForm } from "~/devano/features/auth/RegisterForm";
 import { PasswordResetRequest } from "./PasswordResetRequest";
@@ -29,14 +26,3 @@ export default function AuthInner() {
 		</div>
 	);
 }
-"#;
-
-pub const AUTH_INNER: UiComponent = UiComponent {
-    name: "auth-inner",
-    filename: "AuthInner.tsx",
-    contents: AUTH_INNER_LIT,
-    description: "The view controller for Auth.",
-    long_description: "Handles switches between view states.",
-    folder_path: "features/auth",
-    npm_deps: &[],
-};
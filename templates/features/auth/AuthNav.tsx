@@ -1,6 +1,3 @@
-use crate::library::client::utils::utils::UiComponent;
-
-pub const AUTH_NAV_LIT: &str = r#"
 import { useAuth, ViewState } from "~/devano/features/auth/AuthState";
 import { SingleSimpleSeparator } from "~/devano/atoms/decorators/Separator";
 import { AnchorButton } from "~/devano/atoms/buttons/AnchorButton";
@@ -35,14 +32,3 @@ export function AuthNav() {
 		</div>
 	);
 }
-"#;
-
-pub const AUTH_NAV: UiComponent = UiComponent {
-    name: "auth-nav",
-    filename: "AuthNav.tsx",
-    contents: AUTH_NAV_LIT,
-    description: "Navigation for auth - switch between Signup and Login, or Reset Password",
-    long_description: "Switches view in state, no page refresh :)",
-    folder_path: "features/auth",
-    npm_deps: &[],
-};
@@ -1,10 +1,6 @@
-use crate::library::client::utils::utils::UiComponent;
-
-pub const AUTH_STATE_LIT: &str = r#"
 import { createSignal, createContext, useContext } from "solid-js";
 import { z } from "zod";
 import type { JSX, ParentProps, Accessor, Setter } from "solid-js";
-import type { ZodType } from "zod";
 
 export enum ViewState {
 	LogIn = "log-in",
@@ -14,6 +10,30 @@ export enum ViewState {
 	ResetPasswordFinal = "reset-password-final",
 }
 
+const emailSchema = z.string().email("Enter a valid email address");
+const passwordSchema = z
+	.string()
+	.min(8, "Password must be at least 8 characters long");
+
+// one schema per form - `createForm` takes field names and messages from these
+export const logInSchema = z.object({
+	email: emailSchema,
+	password: passwordSchema,
+});
+export const registerSchema = z
+	.object({
+		email: emailSchema,
+		password: passwordSchema,
+		confirm: z.string(),
+	})
+	.refine((values) => values.password === values.confirm, {
+		message: "Passwords don't match",
+		path: ["confirm"],
+	});
+export const resetRequestSchema = z.object({
+	email: emailSchema,
+});
+
 type StateControl = {
 	get: Accessor<any>;
 	set: Setter<any>;
@@ -35,8 +55,11 @@ type AuthContextType = {
 		error: StateControl;
 	};
 	schema: {
-		email: ZodType;
-		password: ZodType;
+		email: typeof emailSchema;
+		password: typeof passwordSchema;
+		logIn: typeof logInSchema;
+		register: typeof registerSchema;
+		resetRequest: typeof resetRequestSchema;
 	};
 	magic: {
 		code_length: number;
@@ -46,12 +69,6 @@ type AuthContextType = {
 const AuthContext = createContext<AuthContextType>();
 
 export const AuthProvider = (props: any) => {
-	const emailSchema = z.object({
-		email: z.string().email(),
-	});
-	const passwordSchema = z.object({
-		password: z.string().min(8, "Password must be at least 8 characters long"),
-	});
 	const challenge_code_length = 6; // fml magic numbers - updates to the challenge code generator need you to update this magic number too
 	const [view, setView] = createSignal<ViewState>(ViewState.LogIn);
 	const [email, set_email] = createSignal<string>();
@@ -87,6 +104,9 @@ export const AuthProvider = (props: any) => {
 	const schema = {
 		email: emailSchema,
 		password: passwordSchema,
+		logIn: logInSchema,
+		register: registerSchema,
+		resetRequest: resetRequestSchema,
 	};
 	const magic = {
 		code_length: challenge_code_length,
@@ -105,14 +125,3 @@ export const useAuth = () => {
 	}
 	return context;
 };
-"#;
-
-pub const AUTH_STATE: UiComponent = UiComponent {
-    name: "auth-state",
-    filename: "AuthState.tsx",
-    contents: AUTH_STATE_LIT,
-    description: "Context for handling Auth views",
-    long_description: "",
-    folder_path: "state",
-    npm_deps: &[],
-};
@@ -0,0 +1,62 @@
+import { Button } from "~/devano/atoms/buttons/Button";
+import { Form, FormField, createForm } from "~/devano/atoms/inputs/Form";
+import { AuthNav } from "~/devano/features/auth/AuthNav";
+import { Card } from "~/devano/atoms/layout/Card";
+import { Heading } from "~/devano/atoms/layout/Heading";
+import { SimpleSeparator } from "~/devano/atoms/decorators/Separator";
+import { api } from "~/devano/api";
+import { useToast } from "~/devano/atoms/feedback/Toast";
+import { useAuth } from "./AuthState";
+
+export function LogInForm() {
+	const { state, schema } = useAuth();
+	const toast = useToast();
+	const form = createForm({
+		schema: schema.logIn,
+		initial: { email: state.email.get() ?? "", password: "" },
+		onSubmit: async (values) => {
+			// remembered so the password reset view starts with it filled in
+			state.email.set(values.email);
+			let loginResponse = await api.auth.login(values);
+			if (loginResponse instanceof Error) {
+				toast.error(loginResponse.message, "Couldn't log in");
+				return;
+			}
+			state.error.set(null);
+			toast.success("You're logged in.");
+		},
+	});
+	return (
+		<Card class="max-w-[65ch]">
+			<Heading as="h2">Log In</Heading>
+			<Form form={form}>
+				<FormField
+					form={form}
+					name="email"
+					type="email"
+					label="Email"
+					placeholder="Enter your email"
+					autocomplete="email"
+				/>
+				<FormField
+					form={form}
+					name="password"
+					type="password"
+					label="Password"
+					autocomplete="current-password"
+				/>
+				<Button
+					type="submit"
+					loading={form.submitting()}
+				>
+					Log In
+				</Button>
+			</Form>
+			<SimpleSeparator
+				direction={"horizontal"}
+				label="OR"
+			/>
+			<AuthNav />
+		</Card>
+	);
+}
@@ -0,0 +1,50 @@
+import { OTPInput } from "~/devano/atoms/inputs/OTPInput";
+import { Card } from "~/devano/atoms/layout/Card";
+import { Heading } from "~/devano/atoms/layout/Heading";
+import { SimpleSeparator } from "~/devano/atoms/decorators/Separator";
+import { ErrorMessage } from "~/devano/atoms/feedback/ErrorMessage";
+import { useToast } from "~/devano/atoms/feedback/Toast";
+import { api } from "~/devano/api";
+import { AuthNav } from "./AuthNav";
+import { useAuth } from "./AuthState";
+
+export function PasswordResetChallenge() {
+	const { state, setViewTo, magic } = useAuth();
+	const toast = useToast();
+	// the OTP input submits as soon as every box is filled
+	async function submitChallenge(code: string) {
+		let challengeResponse = await api.auth.submitResetChallenge({ code });
+		if (challengeResponse instanceof Error) {
+			toast.error(challengeResponse.message);
+			return;
+		}
+		if (!challengeResponse.success) {
+			state.error.set("That code didn't match - check your email and try again");
+			return;
+		}
+		state.error.set(null);
+		toast.success("Code accepted - log in to continue.");
+		// there's no set-new-password route yet, so head back to log in
+		setViewTo.login();
+	}
+	return (
+		<Card class="max-w-[65ch]">
+			<Heading as="h2">Check Your Email</Heading>
+			<div class="flex flex-col gap-[12px]">
+				<OTPInput
+					label="Reset Code"
+					length={magic.code_length}
+					onComplete={submitChallenge}
+				/>
+				<ErrorMessage when={state.error.get()}>
+					{state.error.get()}
+				</ErrorMessage>
+			</div>
+			<SimpleSeparator
+				direction={"horizontal"}
+				label="OR"
+			/>
+			<AuthNav />
+		</Card>
+	);
+}
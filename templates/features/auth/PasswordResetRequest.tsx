@@ -0,0 +1,56 @@
+import { Button } from "~/devano/atoms/buttons/Button";
+import { Form, FormField, createForm } from "~/devano/atoms/inputs/Form";
+import { Card } from "~/devano/atoms/layout/Card";
+import { Heading } from "~/devano/atoms/layout/Heading";
+import { SimpleSeparator } from "~/devano/atoms/decorators/Separator";
+import { useToast } from "~/devano/atoms/feedback/Toast";
+import { api } from "~/devano/api";
+import { AuthNav } from "./AuthNav";
+import { useAuth } from "./AuthState";
+
+export function PasswordResetRequest() {
+	const { state, schema, setViewTo } = useAuth();
+	const toast = useToast();
+	const form = createForm({
+		schema: schema.resetRequest,
+		initial: { email: state.email.get() ?? "" },
+		onSubmit: async (values) => {
+			// kept so the other auth views start with it filled in
+			state.email.set(values.email);
+			let resetResponse = await api.auth.requestReset(values);
+			if (resetResponse instanceof Error) {
+				toast.error(resetResponse.message, "Couldn't send a reset code");
+				return;
+			}
+			state.error.set(null);
+			toast.success("We've emailed you a reset code.");
+			setViewTo.resetpasswordchallenge();
+		},
+	});
+	return (
+		<Card class="max-w-[65ch]">
+			<Heading as="h2">Reset Password</Heading>
+			<Form form={form}>
+				<FormField
+					form={form}
+					name="email"
+					type="email"
+					label="Email"
+					placeholder="Enter your email"
+					autocomplete="email"
+				/>
+				<Button
+					type="submit"
+					loading={form.submitting()}
+				>
+					Send Reset Code
+				</Button>
+			</Form>
+			<SimpleSeparator
+				direction={"horizontal"}
+				label="OR"
+			/>
+			<AuthNav />
+		</Card>
+	);
+}
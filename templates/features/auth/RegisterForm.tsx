@@ -0,0 +1,71 @@
+import { Button } from "~/devano/atoms/buttons/Button";
+import { Form, FormField, createForm } from "~/devano/atoms/inputs/Form";
+import { AuthNav } from "~/devano/features/auth/AuthNav";
+import { Card } from "~/devano/atoms/layout/Card";
+import { Heading } from "~/devano/atoms/layout/Heading";
+import { SimpleSeparator } from "~/devano/atoms/decorators/Separator";
+import { api } from "~/devano/api";
+import { useToast } from "~/devano/atoms/feedback/Toast";
+import { useAuth } from "./AuthState";
+
+export function RegisterForm() {
+	const { state, schema } = useAuth();
+	const toast = useToast();
+	const form = createForm({
+		schema: schema.register,
+		initial: { email: state.email.get() ?? "", password: "", confirm: "" },
+		onSubmit: async (values) => {
+			state.email.set(values.email);
+			let registerResponse = await api.auth.register({
+				email: values.email,
+				password: values.password,
+			});
+			if (registerResponse instanceof Error) {
+				toast.error(registerResponse.message, "Couldn't register");
+				return;
+			}
+			state.error.set(null);
+			toast.success("Your account is ready.");
+		},
+	});
+	return (
+		<Card class="max-w-[65ch]">
+			<Heading as="h2">Register</Heading>
+			<Form form={form}>
+				<FormField
+					form={form}
+					name="email"
+					type="email"
+					label="Email"
+					placeholder="Enter your email"
+					autocomplete="email"
+				/>
+				<FormField
+					form={form}
+					name="password"
+					type="password"
+					label="Password"
+					autocomplete="new-password"
+				/>
+				<FormField
+					form={form}
+					name="confirm"
+					type="password"
+					label="Confirm Password"
+					autocomplete="new-password"
+				/>
+				<Button
+					type="submit"
+					loading={form.submitting()}
+				>
+					Register
+				</Button>
+			</Form>
+			<SimpleSeparator
+				direction={"horizontal"}
+				label="OR"
+			/>
+			<AuthNav />
+		</Card>
+	);
+}
@@ -0,0 +1,199 @@
+import { Accessor, createEffect, createSignal, onCleanup } from "solid-js";
+import { isServer } from "solid-js/web";
+
+export type Placement = "top" | "bottom" | "left" | "right";
+export type Align = "start" | "center" | "end";
+
+interface DismissOptions {
+	/** Whether the overlay is showing - listeners only run while it is. */
+	open: Accessor<boolean>;
+	/** Called on Escape or a pointer down outside every element in `inside`. */
+	onDismiss: () => void;
+	/** The overlay plus anything that shouldn't count as "outside" (its trigger, usually). */
+	inside: () => (HTMLElement | undefined)[];
+	escape?: boolean;
+	clickOutside?: boolean;
+}
+
+/**
+ * Escape-to-close and click-outside for any overlay. Listeners are attached while `open()`
+ * is true and removed when it flips or the owner is disposed.
+ */
+export function createDismiss(options: DismissOptions) {
+	if (isServer) return;
+	createEffect(() => {
+		if (!options.open()) return;
+		const onKeyDown = (evt: KeyboardEvent) => {
+			if (options.escape !== false && evt.key === "Escape") {
+				evt.stopPropagation();
+				options.onDismiss();
+			}
+		};
+		const onPointerDown = (evt: PointerEvent) => {
+			if (options.clickOutside === false) return;
+			const target = evt.target as Node;
+			const inside = options
+				.inside()
+				.some((element) => element?.contains(target));
+			if (!inside) options.onDismiss();
+		};
+		document.addEventListener("keydown", onKeyDown);
+		document.addEventListener("pointerdown", onPointerDown);
+		onCleanup(() => {
+			document.removeEventListener("keydown", onKeyDown);
+			document.removeEventListener("pointerdown", onPointerDown);
+		});
+	});
+}
+
+const FOCUSABLE = [
+	"a[href]",
+	"button:not([disabled])",
+	"input:not([disabled])",
+	"select:not([disabled])",
+	"textarea:not([disabled])",
+	"[tabindex]:not([tabindex='-1'])",
+].join(",");
+
+export function focusableIn(container: HTMLElement): HTMLElement[] {
+	return Array.from(container.querySelectorAll<HTMLElement>(FOCUSABLE));
+}
+
+/**
+ * Keeps Tab/Shift+Tab inside `container` while `active()`, focuses its first focusable
+ * element on open and hands focus back to whatever had it on close.
+ */
+export function createFocusTrap(
+	container: () => HTMLElement | undefined,
+	active: Accessor<boolean>,
+) {
+	if (isServer) return;
+	createEffect(() => {
+		const element = container();
+		if (!active() || !element) return;
+		const previous = document.activeElement as HTMLElement | null;
+		(focusableIn(element)[0] ?? element).focus();
+
+		const onKeyDown = (evt: KeyboardEvent) => {
+			if (evt.key !== "Tab") return;
+			const focusable = focusableIn(element);
+			if (focusable.length === 0) {
+				evt.preventDefault();
+				return;
+			}
+			const first = focusable[0];
+			const last = focusable[focusable.length - 1];
+			if (evt.shiftKey && document.activeElement === first) {
+				evt.preventDefault();
+				last.focus();
+			} else if (!evt.shiftKey && document.activeElement === last) {
+				evt.preventDefault();
+				first.focus();
+			}
+		};
+		element.addEventListener("keydown", onKeyDown);
+		onCleanup(() => {
+			element.removeEventListener("keydown", onKeyDown);
+			previous?.focus();
+		});
+	});
+}
+
+/**
+ * Where to put a `floating` element of the given size next to `anchor`, in viewport
+ * coordinates - flipped to the opposite side when it would leave the viewport.
+ */
+export function computePosition(
+	anchor: DOMRect,
+	floating: { width: number; height: number },
+	placement: Placement = "bottom",
+	align: Align = "center",
+	offset = 6,
+): { top: number; left: number; placement: Placement } {
+	const viewport = { width: window.innerWidth, height: window.innerHeight };
+	const fits: Record<Placement, boolean> = {
+		top: anchor.top - floating.height - offset >= 0,
+		bottom: anchor.bottom + floating.height + offset <= viewport.height,
+		left: anchor.left - floating.width - offset >= 0,
+		right: anchor.right + floating.width + offset <= viewport.width,
+	};
+	const opposite: Record<Placement, Placement> = {
+		top: "bottom",
+		bottom: "top",
+		left: "right",
+		right: "left",
+	};
+	const side =
+		!fits[placement] && fits[opposite[placement]] ? opposite[placement] : placement;
+
+	const alongX = {
+		start: anchor.left,
+		center: anchor.left + anchor.width / 2 - floating.width / 2,
+		end: anchor.right - floating.width,
+	}[align];
+	const alongY = {
+		start: anchor.top,
+		center: anchor.top + anchor.height / 2 - floating.height / 2,
+		end: anchor.bottom - floating.height,
+	}[align];
+
+	const position = {
+		top: { top: anchor.top - floating.height - offset, left: alongX },
+		bottom: { top: anchor.bottom + offset, left: alongX },
+		left: { top: alongY, left: anchor.left - floating.width - offset },
+		right: { top: alongY, left: anchor.right + offset },
+	}[side];
+	const clamp = (value: number, max: number) =>
+		Math.min(Math.max(value, offset), Math.max(offset, max - offset));
+	return {
+		top: clamp(position.top, viewport.height - floating.height),
+		left: clamp(position.left, viewport.width - floating.width),
+		placement: side,
+	};
+}
+
+/**
+ * Tracks `computePosition` for a floating element while `open()`, following scrolls and
+ * resizes. Use the result as `position: fixed` coordinates.
+ */
+export function createPosition(
+	anchor: () => HTMLElement | undefined,
+	floating: () => HTMLElement | undefined,
+	open: Accessor<boolean>,
+	placement: Placement = "bottom",
+	align: Align = "center",
+) {
+	const [position, setPosition] = createSignal({ top: 0, left: 0, placement });
+	if (isServer) return position;
+	createEffect(() => {
+		const anchorElement = anchor();
+		const floatingElement = floating();
+		if (!open() || !anchorElement || !floatingElement) return;
+		const update = () =>
+			setPosition(
+				computePosition(
+					anchorElement.getBoundingClientRect(),
+					floatingElement.getBoundingClientRect(),
+					placement,
+					align,
+				),
+			);
+		update();
+		window.addEventListener("scroll", update, true);
+		window.addEventListener("resize", update);
+		onCleanup(() => {
+			window.removeEventListener("scroll", update, true);
+			window.removeEventListener("resize", update);
+		});
+	});
+	return position;
+}
+
+/** What an overlay hands its `trigger` render prop - spread it onto the trigger element. */
+export interface TriggerProps {
+	ref: (element: HTMLElement) => void;
+	onClick: () => void;
+	"aria-expanded": boolean;
+	"aria-controls": string;
+	"aria-haspopup": "dialog" | "menu";
+}